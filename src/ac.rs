@@ -0,0 +1,262 @@
+use num::{
+    complex::ComplexFloat,
+    Complex,
+    Float,
+};
+
+use crate::{
+    quantity::{
+        Quantity,
+        QuantityType,
+    },
+    si::{
+        ApparentPower,
+        ApparentPowerKind,
+        Capacitance64,
+        ElectricConductivity64,
+        ElectricCurrent64,
+        ElectricInducance64,
+        ElectricPotential64,
+        ElectricResistance64,
+        Frequency64,
+        Power64,
+        ReactivePower,
+        Unitless,
+    },
+    value_type::{
+        ComplexValueType,
+        RealValueType,
+        ValueType,
+    },
+};
+
+// Dimensions are taken from concrete quantities, so the value type can be inferred from the arguments
+type Dim<Q> = <Q as QuantityType>::Dimension;
+type Real<T, Q> = Quantity<T, Dim<Q>, ()>;
+
+/// Complex impedance, with the resistance as real and the reactance as imaginary part
+pub type Impedance<T> = Quantity<Complex<T>, Dim<ElectricResistance64>, ()>;
+/// Complex admittance, the inverse of the impedance
+pub type Admittance<T> = Quantity<Complex<T>, Dim<ElectricConductivity64>, ()>;
+/// A voltage phasor. Magnitudes are RMS values.
+pub type VoltagePhasor<T> = Quantity<Complex<T>, Dim<ElectricPotential64>, ()>;
+/// A current phasor. Magnitudes are RMS values.
+pub type CurrentPhasor<T> = Quantity<Complex<T>, Dim<ElectricCurrent64>, ()>;
+/// Complex power, with the real power as real and the reactive power as imaginary part.
+/// Its magnitude is the apparent power, hence the kind.
+pub type ComplexPower<T> = Quantity<Complex<T>, Dim<Power64>, ApparentPowerKind>;
+
+fn angular_frequency<T>(frequency: Real<T, Frequency64>) -> T
+where
+    T: ValueType + RealValueType,
+{
+    frequency.base_value() * T::new_from_real_f64(std::f64::consts::TAU)
+}
+
+/// Impedance of an ideal resistor
+pub fn resistor<T>(resistance: Real<T, ElectricResistance64>) -> Impedance<T>
+where
+    T: ValueType + RealValueType + Float,
+    Complex<T>: ValueType + ComplexValueType,
+{
+    Impedance::new_base(Complex::new(resistance.base_value(), T::ZERO))
+}
+
+/// Impedance of an ideal capacitor at the given frequency, `1 / (jωC)`
+pub fn capacitor<T>(
+    capacitance: Real<T, Capacitance64>,
+    frequency: Real<T, Frequency64>,
+) -> Impedance<T>
+where
+    T: ValueType + RealValueType + Float,
+    Complex<T>: ValueType + ComplexValueType,
+{
+    let reactance = -(angular_frequency(frequency) * capacitance.base_value()).recip();
+    Impedance::new_base(Complex::new(T::ZERO, reactance))
+}
+
+/// Impedance of an ideal inductor at the given frequency, `jωL`
+pub fn inductor<T>(
+    inductance: Real<T, ElectricInducance64>,
+    frequency: Real<T, Frequency64>,
+) -> Impedance<T>
+where
+    T: ValueType + RealValueType + Float,
+    Complex<T>: ValueType + ComplexValueType,
+{
+    let reactance = angular_frequency(frequency) * inductance.base_value();
+    Impedance::new_base(Complex::new(T::ZERO, reactance))
+}
+
+/// Impedance of components in series
+pub fn series<T, I>(impedances: I) -> Impedance<T>
+where
+    T: ValueType + RealValueType + Float,
+    Complex<T>: ValueType + ComplexValueType,
+    I: IntoIterator<Item = Impedance<T>>,
+{
+    Impedance::new_base(
+        impedances
+            .into_iter()
+            .fold(Complex::ZERO, |acc, z| acc + z.base_value()),
+    )
+}
+
+/// Impedance of components in parallel.
+///
+/// Parallel to nothing is an open circuit, which has infinite impedance.
+/// A short circuit in parallel shorts everything, so any zero impedance gives zero.
+pub fn parallel<T, I>(impedances: I) -> Impedance<T>
+where
+    T: ValueType + RealValueType + Float,
+    Complex<T>: ValueType + ComplexValueType,
+    I: IntoIterator<Item = Impedance<T>>,
+{
+    let mut admittance = Complex::ZERO;
+    for z in impedances {
+        let z = z.base_value();
+        if z == Complex::ZERO {
+            return Impedance::new_base(Complex::ZERO);
+        }
+        admittance = admittance + z.inv();
+    }
+    Impedance::new_base(if admittance == Complex::ZERO {
+        Complex::new(T::infinity(), T::ZERO)
+    } else {
+        admittance.inv()
+    })
+}
+
+/// Admittance of an impedance
+pub fn admittance<T>(impedance: Impedance<T>) -> Admittance<T>
+where
+    T: ValueType + RealValueType + Float,
+    Complex<T>: ValueType + ComplexValueType,
+{
+    Admittance::new_base(impedance.base_value().inv())
+}
+
+/// Current through an impedance with the given voltage across it
+pub fn current<T>(voltage: VoltagePhasor<T>, impedance: Impedance<T>) -> CurrentPhasor<T>
+where
+    T: ValueType + RealValueType + Float,
+    Complex<T>: ValueType + ComplexValueType,
+{
+    CurrentPhasor::new_base(voltage.base_value() / impedance.base_value())
+}
+
+/// Voltage across an impedance with the given current through it
+pub fn voltage<T>(current: CurrentPhasor<T>, impedance: Impedance<T>) -> VoltagePhasor<T>
+where
+    T: ValueType + RealValueType + Float,
+    Complex<T>: ValueType + ComplexValueType,
+{
+    VoltagePhasor::new_base(current.base_value() * impedance.base_value())
+}
+
+/// Complex power `S = V·I*` from RMS phasors
+pub fn complex_power<T>(voltage: VoltagePhasor<T>, current: CurrentPhasor<T>) -> ComplexPower<T>
+where
+    T: ValueType + RealValueType + Float,
+    Complex<T>: ValueType + ComplexValueType,
+{
+    ComplexPower::new_base(voltage.base_value() * current.base_value().conj())
+}
+
+/// Real power, the real part of the complex power
+pub fn real_power<T>(power: ComplexPower<T>) -> Real<T, Power64>
+where
+    T: ValueType + RealValueType + Float,
+    Complex<T>: ValueType + ComplexValueType,
+{
+    Quantity::new_base(power.base_value().re)
+}
+
+/// Reactive power, the imaginary part of the complex power
+pub fn reactive_power<T>(power: ComplexPower<T>) -> ReactivePower<T>
+where
+    T: ValueType + RealValueType + Float,
+    Complex<T>: ValueType + ComplexValueType,
+{
+    Quantity::new_base(power.base_value().im)
+}
+
+/// Apparent power, the magnitude of the complex power
+pub fn apparent_power<T>(power: ComplexPower<T>) -> ApparentPower<T>
+where
+    T: ValueType + RealValueType + Float,
+    Complex<T>: ValueType + ComplexValueType + ComplexFloat<Real = T>,
+{
+    Quantity::new_base(power.base_value().abs())
+}
+
+/// Power factor, the ratio of real to apparent power
+pub fn power_factor<T>(power: ComplexPower<T>) -> Unitless<T>
+where
+    T: ValueType + RealValueType + Float,
+    Complex<T>: ValueType + ComplexValueType + ComplexFloat<Real = T>,
+{
+    Quantity::new_base(power.base_value().re / power.base_value().abs())
+}
+
+/// Power factor from the magnitudes of real and apparent power, when no phasors are at hand
+pub fn power_factor_from<T>(real: Real<T, Power64>, apparent: ApparentPower<T>) -> Unitless<T>
+where
+    T: ValueType + RealValueType + Float,
+{
+    real / apparent
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::si::*;
+
+    #[test]
+    fn rc_parallel() {
+        // same circuit as tests/complex_numbers.rs
+        let f = Frequency64::new::<Hertz>(1e4);
+        let z_r = resistor(ElectricResistance64::new::<Ohm>(50.0));
+        let z_c = capacitor(Capacitance64::new::<Farad>(1e-6), f);
+
+        let z = parallel([z_r, z_c]);
+        assert!((z.norm().get::<Ohm>() - 15.1657235527).abs() < 1e-7);
+        assert!((z.arg().get::<Radian>() - (-1.2626272557)).abs() < 1e-7);
+
+        let z_l = inductor(ElectricInducance64::new::<Henry>(1e-3), f);
+        let z = series([z_r, z_l]);
+        assert_eq!(z.re().get::<Ohm>(), 50.0);
+        assert!((z.im().get::<Ohm>() - 62.83185307).abs() < 1e-7);
+        assert!(parallel::<f64, _>([]).re().get::<Ohm>().is_infinite());
+        let short = parallel([z_r, resistor(ElectricResistance64::new::<Ohm>(0.0))]);
+        assert_eq!(short.base_value(), Complex::ZERO);
+    }
+
+    #[test]
+    fn power() {
+        let v = VoltagePhasor::from_polar(
+            ElectricPotential64::new::<Volt>(230.0),
+            Angle::new::<Degree>(0.0),
+        );
+        let z = series([
+            resistor(ElectricResistance64::new::<Ohm>(30.0)),
+            inductor(
+                ElectricInducance64::new::<Henry>(40.0 / std::f64::consts::TAU),
+                Frequency64::new::<Hertz>(1.0),
+            ),
+        ]);
+        let i = current(v, z);
+        assert!((i.norm().get::<Ampere>() - 4.6).abs() < 1e-9);
+        assert!((voltage(i, z) - v).norm().get::<Volt>() < 1e-9);
+
+        let s = complex_power(v, i);
+        assert!((real_power(s).get::<Watt>() - 634.8).abs() < 1e-9);
+        assert!((reactive_power(s).get::<VoltAmpereReactive>() - 846.4).abs() < 1e-9);
+        assert!((apparent_power(s).get::<KiloVoltAmpere>() - 1.058).abs() < 1e-12);
+        assert!((power_factor(s).get::<Ratio>() - 0.6).abs() < 1e-12);
+        let pf = power_factor_from(real_power(s), apparent_power(s));
+        assert!((pf.get::<Ratio>() - 0.6).abs() < 1e-12);
+        let sin_phi: Unitless64 = reactive_power(s) / apparent_power(s);
+        assert!((sin_phi.get::<Ratio>() - 0.8).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,100 @@
+//! Hartree atomic units, with ħ = mₑ = e = 4πε₀ = 1.
+//!
+//! Length is measured in bohr radii, energy in hartrees, mass in electron masses and charge in elementary charges.
+//! ```
+//! # use silib::{atomic_units::AtomicUnit, quantity::QuantityType, si::*};
+//! let bond = Length64::new::<Angstrom>(0.74);
+//! assert!((bond.get::<AtomicUnit>() - 1.398_397).abs() < 1e-6);
+//! ```
+
+use typenum::{
+    Integer,
+    Z0,
+};
+
+use crate::{
+    constants::{
+        BOHR_RADIUS,
+        ELECTRON_MASS,
+        ELEMENTARY_CHARGE,
+        HARTREE_ENERGY,
+        HBAR,
+    },
+    dimension::{
+        Dimension,
+        SiDim,
+    },
+    quantity::QuantityType,
+    unit::{
+        QuantityConversion,
+        Unit,
+    },
+    value_type::ValueType,
+};
+
+/// Hartree atomic units
+pub struct AtomicUnit {}
+
+impl Unit for AtomicUnit {
+    fn long_name() -> &'static str {
+        "AtomicUnit"
+    }
+
+    fn print_name() -> &'static str {
+        "a.u."
+    }
+}
+
+/// The factor of `AtomicUnit` for the dimension L^l M^m T^t I^i
+fn factor<L, M, Ti, I>() -> f64
+where
+    L: Integer,
+    M: Integer,
+    Ti: Integer,
+    I: Integer,
+{
+    let time = HBAR.base_value() / HARTREE_ENERGY.base_value();
+    let current = ELEMENTARY_CHARGE.base_value() / time;
+    BOHR_RADIUS.base_value().powi(L::to_i32())
+        * ELECTRON_MASS.base_value().powi(M::to_i32())
+        * time.powi(Ti::to_i32())
+        * current.powi(I::to_i32())
+}
+
+impl<T, L, M, Ti, I, K> QuantityConversion<T, SiDim<L, M, Ti, I, Z0, Z0, Z0>, K> for AtomicUnit
+where
+    T: ValueType,
+    L: Integer,
+    M: Integer,
+    Ti: Integer,
+    I: Integer,
+    SiDim<L, M, Ti, I, Z0, Z0, Z0>: Dimension,
+{
+    fn linear() -> Option<(T, T)> {
+        Some((T::new_from_real_f64(factor::<L, M, Ti, I>()), T::ZERO))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::si::*;
+
+    #[test]
+    fn hydrogen() {
+        let ground_state = Energy64::new::<Electronvolt>(-13.605_693_122_994);
+        assert!((ground_state.get::<AtomicUnit>() + 0.5).abs() < 1e-9);
+        assert!(
+            (Length64::new::<AtomicUnit>(1.0).get::<Angstrom>() - 0.529_177_210_903).abs() < 1e-12
+        );
+
+        let time = Time64::new::<AtomicUnit>(1.0);
+        assert!((time.get::<Second>() - 2.418_884_326e-17).abs() < 1e-25);
+        let charge = ElectricCharge64::new::<AtomicUnit>(-1.0);
+        assert_eq!(charge.get::<Coulomb>(), -1.602_176_634e-19);
+
+        // the electron in the ground state moves at α·c, one atomic unit of velocity
+        let velocity = Velocity64::new::<AtomicUnit>(1.0);
+        assert!((velocity.get::<MetresPerSecond>() - 2.187_691_263e6).abs() < 1.0);
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{
+    constants::{
+        constant,
+        AVOGADRO,
+        GAS_CONSTANT,
+    },
+    quantity::WithValue,
+    si::*,
+    value_type::{
+        RealValueType,
+        ValueType,
+    },
+};
+
+/// Amount of substance in the given mass
+pub fn amount_from_mass<T>(
+    mass: WithValue<T, Mass64>,
+    molar_mass: WithValue<T, MolarMass64>,
+) -> WithValue<T, Amount64>
+where
+    T: ValueType + RealValueType,
+{
+    mass / molar_mass
+}
+
+/// Mass of the given amount of substance
+pub fn mass_from_amount<T>(
+    amount: WithValue<T, Amount64>,
+    molar_mass: WithValue<T, MolarMass64>,
+) -> WithValue<T, Mass64>
+where
+    T: ValueType + RealValueType,
+{
+    amount * molar_mass
+}
+
+/// Number of particles in the given amount of substance
+pub fn particle_count<T>(amount: WithValue<T, Amount64>) -> WithValue<T, Unitless64>
+where
+    T: ValueType + RealValueType,
+{
+    amount * constant(AVOGADRO)
+}
+
+/// Pressure of an ideal gas, `p = nRT / V`
+pub fn ideal_gas_pressure<T>(
+    amount: WithValue<T, Amount64>,
+    temperature: WithValue<T, ThermodynamicTemperature64>,
+    volume: WithValue<T, Volume64>,
+) -> WithValue<T, Pressure64>
+where
+    T: ValueType + RealValueType,
+{
+    amount * constant(GAS_CONSTANT) * temperature.unrestricted() / volume
+}
+
+/// Volume of an ideal gas, `V = nRT / p`
+pub fn ideal_gas_volume<T>(
+    amount: WithValue<T, Amount64>,
+    temperature: WithValue<T, ThermodynamicTemperature64>,
+    pressure: WithValue<T, Pressure64>,
+) -> WithValue<T, Volume64>
+where
+    T: ValueType + RealValueType,
+{
+    amount * constant(GAS_CONSTANT) * temperature.unrestricted() / pressure
+}
+
+/// Amount of substance of an ideal gas, `n = pV / RT`
+pub fn ideal_gas_amount<T>(
+    pressure: WithValue<T, Pressure64>,
+    volume: WithValue<T, Volume64>,
+    temperature: WithValue<T, ThermodynamicTemperature64>,
+) -> WithValue<T, Amount64>
+where
+    T: ValueType + RealValueType,
+{
+    pressure * volume / (temperature.unrestricted() * constant(GAS_CONSTANT))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::quantity::QuantityType;
+
+    #[test]
+    fn solution() {
+        // 5.844 g of table salt in half a litre of water
+        let molar_mass = MolarMass64::new::<GramPerMole>(58.44);
+        let amount = amount_from_mass(Mass64::new::<Gram>(5.844), molar_mass);
+        assert!((amount.get::<MilliMole>() - 100.0).abs() < 1e-9);
+        assert!((mass_from_amount(amount, molar_mass).get::<Gram>() - 5.844).abs() < 1e-12);
+
+        let concentration: MolarConcentration64 = amount / Volume64::new::<CubicMetre>(0.5e-3);
+        assert!((concentration.get::<Molar>() - 0.2).abs() < 1e-12);
+        assert!((concentration.get::<MilliMolar>() - 200.0).abs() < 1e-9);
+        assert!((concentration.get::<MolePerLitre>() - 0.2).abs() < 1e-12);
+
+        let molality: Molality64 = amount / Mass64::new::<Kilogram>(0.5);
+        assert!((molality.get::<MolePerKilogram>() - 0.2).abs() < 1e-12);
+        assert!((particle_count(amount).get::<Ratio>() - 6.02214076e22).abs() < 1e10);
+    }
+
+    #[test]
+    fn ideal_gas() {
+        let amount = Amount64::new::<Mole>(1.0);
+        let temperature = ThermodynamicTemperature64::new::<Celsius>(0.0);
+        let pressure = Pressure64::new::<Pascal>(101325.0);
+
+        let volume = ideal_gas_volume(amount, temperature, pressure);
+        assert!((volume.get::<CubicMetre>() - 22.413969e-3).abs() < 1e-8);
+        let molar_volume: MolarVolume64 = volume / amount;
+        assert!((molar_volume.get::<LitrePerMole>() - 22.413969).abs() < 1e-5);
+
+        assert!(
+            (ideal_gas_pressure(amount, temperature, volume) - pressure)
+                .abs()
+                .get::<Pascal>()
+                < 1e-6
+        );
+        assert!(
+            (ideal_gas_amount(pressure, volume, temperature).get::<Mole>() - 1.0).abs() < 1e-12
+        );
+
+        let single = ideal_gas_volume(
+            Amount::<f32>::new::<Mole>(1.0),
+            ThermodynamicTemperature::<f32>::new::<Kelvin>(273.15),
+            Pressure::<f32>::new::<Pascal>(101325.0),
+        );
+        assert!((single.get::<Litre>() - 22.41397).abs() < 1e-4);
+
+        let energy: MolarEnergy64 = GAS_CONSTANT * TemperatureInterval64::new::<Kelvin>(100.0);
+        assert!((energy.get::<KiloJoulePerMole>() - 0.8314462618).abs() < 1e-12);
+    }
+}
@@ -1,4 +1,66 @@
-use crate::si::*;
+use typenum::{
+    N3,
+    N4,
+    P1,
+    Z0,
+};
+
+use crate::{
+    dimension::SiDim,
+    quantity::{
+        Quantity,
+        QuantityType,
+        WithValue,
+    },
+    si::*,
+    value_type::ValueType,
+};
 
 pub const EPSILON_0: ElectricPermittivity64 =
     ElectricPermittivity::<f64>::new_base(8.854187818814e-12);
+
+/// Vacuum magnetic permeability
+pub const MU_0: MagneticPermeability64 = MagneticPermeability::<f64>::new_base(1.25663706212e-6);
+
+/// Luminous efficacy of monochromatic radiation at 540 THz (about 555 nm), where the eye is most sensitive
+pub const MAX_LUMINOUS_EFFICACY: LuminousEfficacy64 = LuminousEfficacy::<f64>::new_base(683.0);
+
+/// Avogadro constant, the number of particles in one mole
+pub const AVOGADRO: InverseAmount64 = InverseAmount::<f64>::new_base(6.02214076e23);
+
+/// Molar gas constant
+pub const GAS_CONSTANT: MolarHeatCapacity64 = MolarHeatCapacity::<f64>::new_base(8.314462618);
+
+/// W/(m²·K⁴)
+type StefanBoltzmannDim = SiDim<Z0, P1, N3, Z0, N4, Z0, Z0>;
+
+/// Stefan–Boltzmann constant
+pub const STEFAN_BOLTZMANN: Quantity<f64, StefanBoltzmannDim, ()> =
+    Quantity::new_base(5.670374419e-8);
+
+/// Speed of light in vacuum
+pub const SPEED_OF_LIGHT: Velocity64 = Velocity::<f64>::new_base(299_792_458.0);
+
+/// Reduced Planck constant
+pub const HBAR: Action64 = Action::<f64>::new_base(1.054_571_817e-34);
+
+/// Elementary charge
+pub const ELEMENTARY_CHARGE: ElectricCharge64 = ElectricCharge::<f64>::new_base(1.602_176_634e-19);
+
+/// Electron rest mass
+pub const ELECTRON_MASS: Mass64 = Mass::<f64>::new_base(9.109_383_701_5e-31);
+
+/// Hartree energy, the atomic unit of energy
+pub const HARTREE_ENERGY: Energy64 = Energy::<f64>::new_base(4.359_744_722_207_1e-18);
+
+/// Bohr radius, the atomic unit of length
+pub const BOHR_RADIUS: Length64 = Length::<f64>::new_base(5.291_772_109_03e-11);
+
+/// A constant with its value converted to `T`, for use in generic code
+pub(crate) fn constant<T, Q>(constant: Q) -> WithValue<T, Q>
+where
+    T: ValueType,
+    Q: QuantityType<DataType = f64>,
+{
+    Quantity::new_base(T::new_from_real_f64(constant.base_value()))
+}
@@ -19,6 +19,11 @@ use typenum::{
 /// Should increase readability by a massive amount
 pub trait Dimension {
     type DimType;
+    /// The exponents of length, mass, time, current, temperature, amount and luminous intensity.
+    ///
+    /// Only the runtime registry and unit systems need them, so other dimension types may leave this out;
+    /// using them with the registry then fails to compile.
+    const EXPONENTS: [i32; 7] = panic!("this dimension has no SI exponents");
 }
 
 /// If the dimension implements this, 1/dim is available.
@@ -213,16 +218,32 @@ impl<Length, Mass, Time, Current, Temperature, Amount, Intensity> Dimension
 where
     Length: typenum::Integer,
     Mass: typenum::Integer,
+    Time: typenum::Integer,
     Current: typenum::Integer,
     Temperature: typenum::Integer,
     Amount: typenum::Integer,
     Intensity: typenum::Integer,
 {
     type DimType = SiDim<Length, Mass, Time, Current, Temperature, Amount, Intensity>;
+
+    const EXPONENTS: [i32; 7] = [
+        Length::I32,
+        Mass::I32,
+        Time::I32,
+        Current::I32,
+        Temperature::I32,
+        Amount::I32,
+        Intensity::I32,
+    ];
 }
 
 use typenum::{
+    N1,
+    N2,
+    N3,
     P1,
+    P2,
+    P3,
     Z0,
 };
 
@@ -242,3 +263,13 @@ pub type TemperatureDim = SiDim<Z0, Z0, Z0, Z0, P1, Z0, Z0>;
 pub type AmountDim = SiDim<Z0, Z0, Z0, Z0, Z0, P1, Z0>;
 /// The dimension of luminous intensity
 pub type IntensityDim = SiDim<Z0, Z0, Z0, Z0, Z0, Z0, P1>;
+/// The dimension of power, needed to put kinds on it
+pub type PowerDim = SiDim<P2, P1, N3, Z0, Z0, Z0, Z0>;
+/// The dimension of area, needed to put kinds on it
+pub type AreaDim = SiDim<P2, Z0, Z0, Z0, Z0, Z0, Z0>;
+/// The dimension of volume, needed to put kinds on it
+pub type VolumeDim = SiDim<P3, Z0, Z0, Z0, Z0, Z0, Z0>;
+/// The dimension of energy, needed to put kinds on it
+pub type EnergyDim = SiDim<P2, P1, N2, Z0, Z0, Z0, Z0>;
+/// The dimension of pressure, needed to put kinds on it
+pub type PressureDim = SiDim<N1, P1, N2, Z0, Z0, Z0, Z0>;
@@ -0,0 +1,171 @@
+use num::Float;
+
+use crate::{
+    quantity::{
+        QuantityType,
+        WithValue,
+    },
+    si::*,
+    value_type::{
+        RealValueType,
+        ValueType,
+    },
+};
+
+/// Volumetric flow rate of a fluid moving through a cross section
+pub fn volumetric_flow_rate<T>(
+    velocity: WithValue<T, Velocity64>,
+    area: WithValue<T, Area64>,
+) -> WithValue<T, VolumetricFlowRate64>
+where
+    T: ValueType + RealValueType,
+{
+    velocity * area
+}
+
+/// Mass flow rate of a fluid with the given density
+pub fn mass_flow_rate<T>(
+    density: WithValue<T, Density64>,
+    flow_rate: WithValue<T, VolumetricFlowRate64>,
+) -> WithValue<T, MassFlowRate64>
+where
+    T: ValueType + RealValueType,
+{
+    density * flow_rate
+}
+
+/// Reynolds number `ρvL / μ`, the ratio of inertial to viscous forces
+pub fn reynolds_number<T>(
+    density: WithValue<T, Density64>,
+    velocity: WithValue<T, Velocity64>,
+    length: WithValue<T, Length64>,
+    viscosity: WithValue<T, DynamicViscosity64>,
+) -> WithValue<T, ReynoldsNumber64>
+where
+    T: ValueType + RealValueType,
+{
+    let re: WithValue<T, Unitless64> = density * velocity * length / viscosity;
+    WithValue::<T, ReynoldsNumber64>::new_base(re.base_value())
+}
+
+/// Reynolds number `vL / ν` using the kinematic viscosity
+pub fn reynolds_number_kinematic<T>(
+    velocity: WithValue<T, Velocity64>,
+    length: WithValue<T, Length64>,
+    viscosity: WithValue<T, KinematicViscosity64>,
+) -> WithValue<T, ReynoldsNumber64>
+where
+    T: ValueType + RealValueType,
+{
+    let re: WithValue<T, Unitless64> = velocity * length / viscosity;
+    WithValue::<T, ReynoldsNumber64>::new_base(re.base_value())
+}
+
+/// Prandtl number `c_p μ / k`, the ratio of momentum to thermal diffusivity
+pub fn prandtl_number<T>(
+    specific_heat: WithValue<T, SpecificHeatCapacity64>,
+    viscosity: WithValue<T, DynamicViscosity64>,
+    conductivity: WithValue<T, ThermalConductivity64>,
+) -> WithValue<T, PrandtlNumber64>
+where
+    T: ValueType + RealValueType,
+{
+    let pr: WithValue<T, Unitless64> = specific_heat * viscosity / conductivity;
+    WithValue::<T, PrandtlNumber64>::new_base(pr.base_value())
+}
+
+/// Nusselt number `hL / k`, the ratio of convective to conductive heat transfer
+pub fn nusselt_number<T>(
+    coefficient: WithValue<T, HeatTransferCoefficient64>,
+    length: WithValue<T, Length64>,
+    conductivity: WithValue<T, ThermalConductivity64>,
+) -> WithValue<T, NusseltNumber64>
+where
+    T: ValueType + RealValueType,
+{
+    let nu: WithValue<T, Unitless64> = coefficient * length / conductivity;
+    WithValue::<T, NusseltNumber64>::new_base(nu.base_value())
+}
+
+/// Mach number, the flow velocity relative to the speed of sound
+pub fn mach_number<T>(
+    velocity: WithValue<T, Velocity64>,
+    speed_of_sound: WithValue<T, Velocity64>,
+) -> WithValue<T, MachNumber64>
+where
+    T: ValueType + RealValueType,
+{
+    let ma: WithValue<T, Unitless64> = velocity / speed_of_sound;
+    WithValue::<T, MachNumber64>::new_base(ma.base_value())
+}
+
+/// Froude number `v / √(gL)`, the ratio of inertial to gravitational forces
+pub fn froude_number<T>(
+    velocity: WithValue<T, Velocity64>,
+    length: WithValue<T, Length64>,
+    gravity: WithValue<T, Acceleration64>,
+) -> WithValue<T, FroudeNumber64>
+where
+    T: ValueType + RealValueType + Float,
+{
+    let fr: WithValue<T, Unitless64> = velocity / (gravity * length).sqrt();
+    WithValue::<T, FroudeNumber64>::new_base(fr.base_value())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pipe_flow() {
+        // water at 20 °C in a 5 cm pipe
+        let density = Density64::new::<KilogramPerCubicMetre>(998.0);
+        let viscosity = DynamicViscosity64::new::<PascalSecond>(1.002e-3);
+        let diameter = Length64::new::<Metre>(0.05);
+        let velocity = Velocity64::new::<MetresPerSecond>(2.0);
+
+        let re = reynolds_number(density, velocity, diameter, viscosity);
+        assert!((re.get::<Ratio>() - 99600.798).abs() < 1e-3);
+        let nu: KinematicViscosity64 = viscosity / density;
+        let re2 = reynolds_number_kinematic(velocity, diameter, nu);
+        assert!((re2.get::<Ratio>() - re.get::<Ratio>()).abs() < 1e-6);
+
+        let area = Area64::new::<SquareMetre>(std::f64::consts::PI * 0.025 * 0.025);
+        let flow = volumetric_flow_rate(velocity, area);
+        assert!((flow.get::<LitrePerMinute>() - 235.619449).abs() < 1e-6);
+        assert!((mass_flow_rate(density, flow).get::<KilogramPerSecond>() - 3.919137).abs() < 1e-6);
+    }
+
+    #[test]
+    fn heat_and_waves() {
+        let cp = SpecificHeatCapacity64::new::<JoulePerKilogramKelvin>(4182.0);
+        let mu = DynamicViscosity64::new::<PascalSecond>(1.002e-3);
+        let k = ThermalConductivity64::new::<WattPerMetreKelvin>(0.598);
+        assert!((prandtl_number(cp, mu, k).get::<Ratio>() - 7.0073).abs() < 1e-4);
+
+        let h = HeatTransferCoefficient64::new::<WattPerSquareMetreKelvin>(1196.0);
+        let nu = nusselt_number(h, Length64::new::<Metre>(0.05), k);
+        assert!((nu.get::<Ratio>() - 100.0).abs() < 1e-9);
+
+        let mach = mach_number(
+            Velocity64::new::<MetresPerSecond>(686.0),
+            Velocity64::new::<MetresPerSecond>(343.0),
+        );
+        assert_eq!(mach.get::<Ratio>(), 2.0);
+        let margin = mach - MachNumber64::new::<Ratio>(1.0);
+        assert_eq!((margin + margin).get::<Ratio>(), 2.0);
+
+        let fr = froude_number(
+            Velocity64::new::<MetresPerSecond>(6.0),
+            Length64::new::<Metre>(0.9),
+            Acceleration64::new::<MetresPerSecondSquared>(10.0),
+        );
+        assert!((fr.get::<Ratio>() - 2.0).abs() < 1e-12);
+
+        let ma = mach_number(
+            Velocity::<f32>::new::<MetresPerSecond>(171.5),
+            Velocity::<f32>::new::<MetresPerSecond>(343.0),
+        );
+        assert_eq!(ma.get::<Ratio>(), 0.5);
+    }
+}
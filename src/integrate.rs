@@ -0,0 +1,302 @@
+use std::ops::Mul;
+
+use num::{
+    traits::ConstZero,
+    Float,
+};
+
+use crate::{
+    dimension::Dimension,
+    kind::KindMul,
+    quantity::{
+        Quantity,
+        QuantityType,
+    },
+    value_type::{
+        RealValueType,
+        ValueType,
+    },
+};
+
+/// The result of integrating `Y` over `X`. Current over time is charge, power over time is energy, and so on.
+pub type Integral<X, Y> = Quantity<
+    <X as QuantityType>::DataType,
+    <<Y as QuantityType>::Dimension as Mul<<X as QuantityType>::Dimension>>::Output,
+    <<Y as QuantityType>::Kind as KindMul<<X as QuantityType>::Kind>>::Output,
+>;
+
+/// Maximum recursion depth of `adaptive_simpson`
+const MAX_ADAPTIVE_DEPTH: u32 = 20;
+
+/// Maximum number of evaluations of the integrand in `adaptive_simpson`
+const MAX_ADAPTIVE_EVALUATIONS: usize = 100_000;
+
+/// Integrate `f` from `a` to `b` using the trapezoidal rule with `intervals` equally spaced intervals.
+pub fn trapezoid<X, Y, F>(f: F, a: X, b: X, intervals: usize) -> Integral<X, Y>
+where
+    X: QuantityType,
+    Y: QuantityType<DataType = X::DataType>,
+    Y::Dimension: Mul<X::Dimension>,
+    <Y::Dimension as Mul<X::Dimension>>::Output: Dimension,
+    Y::Kind: KindMul<X::Kind>,
+    F: Fn(X) -> Y,
+{
+    let intervals = intervals.max(1);
+    let (a, b) = (a.base_value(), b.base_value());
+    let h = (b - a) / X::DataType::new_from_real_f64(intervals as f64);
+    let eval = |i: usize| {
+        f(X::from_base(
+            a + h * X::DataType::new_from_real_f64(i as f64),
+        ))
+        .base_value()
+    };
+
+    let two = X::DataType::new_from_real_f64(2.0);
+    let inner = (1..intervals).fold(X::DataType::ZERO, |acc, i| acc + eval(i));
+    Quantity::new_base(h * ((eval(0) + eval(intervals)) / two + inner))
+}
+
+/// Integrate `f` from `a` to `b` using the composite Simpson rule.
+///
+/// Simpson needs an even number of intervals, so odd values of `intervals` are rounded up.
+pub fn simpson<X, Y, F>(f: F, a: X, b: X, intervals: usize) -> Integral<X, Y>
+where
+    X: QuantityType,
+    Y: QuantityType<DataType = X::DataType>,
+    Y::Dimension: Mul<X::Dimension>,
+    <Y::Dimension as Mul<X::Dimension>>::Output: Dimension,
+    Y::Kind: KindMul<X::Kind>,
+    F: Fn(X) -> Y,
+{
+    let intervals = intervals.max(2).next_multiple_of(2);
+    let (a, b) = (a.base_value(), b.base_value());
+    let h = (b - a) / X::DataType::new_from_real_f64(intervals as f64);
+    let eval = |i: usize| {
+        f(X::from_base(
+            a + h * X::DataType::new_from_real_f64(i as f64),
+        ))
+        .base_value()
+    };
+
+    let (two, four) = (
+        X::DataType::new_from_real_f64(2.0),
+        X::DataType::new_from_real_f64(4.0),
+    );
+    let inner = (1..intervals).fold(X::DataType::ZERO, |acc, i| {
+        acc + eval(i) * if i % 2 == 1 { four } else { two }
+    });
+    let sum = eval(0) + eval(intervals) + inner;
+    Quantity::new_base(sum * h / X::DataType::new_from_real_f64(3.0))
+}
+
+/// Integrate `f` from `a` to `b` using adaptive Simpson quadrature.
+///
+/// Intervals are split until the estimated error drops below `tolerance`, which is given in the unit of the result.
+/// Recursion is limited to a depth of 20 and to 100000 evaluations of `f`, after which the best estimate is used.
+/// Returns `None` if `f` is not finite somewhere it is evaluated.
+pub fn adaptive_simpson<X, Y, F>(
+    f: F,
+    a: X,
+    b: X,
+    tolerance: Integral<X, Y>,
+) -> Option<Integral<X, Y>>
+where
+    X: QuantityType,
+    X::DataType: RealValueType + Float,
+    Y: QuantityType<DataType = X::DataType>,
+    Y::Dimension: Mul<X::Dimension>,
+    <Y::Dimension as Mul<X::Dimension>>::Output: Dimension,
+    Y::Kind: KindMul<X::Kind>,
+    F: Fn(X) -> Y,
+{
+    let eval = |x: X::DataType| f(X::from_base(x)).base_value();
+    let (a, b) = (a.base_value(), b.base_value());
+    let (fa, fb) = (eval(a), eval(b));
+    let m = (a + b) / X::DataType::new_from_real_f64(2.0);
+    let fm = eval(m);
+    let whole = simpson_step(a, b, fa, fm, fb);
+
+    let mut evaluations = MAX_ADAPTIVE_EVALUATIONS - 3;
+    adaptive_step(
+        &eval,
+        (a, b),
+        (fa, fm, fb),
+        whole,
+        tolerance.base_value().abs(),
+        MAX_ADAPTIVE_DEPTH,
+        &mut evaluations,
+    )
+    .map(Quantity::new_base)
+}
+
+fn simpson_step<T>(a: T, b: T, fa: T, fm: T, fb: T) -> T
+where
+    T: ValueType,
+{
+    (b - a) / T::new_from_real_f64(6.0) * (fa + T::new_from_real_f64(4.0) * fm + fb)
+}
+
+fn adaptive_step<T, F>(
+    eval: &F,
+    (a, b): (T, T),
+    (fa, fm, fb): (T, T, T),
+    whole: T,
+    tolerance: T,
+    depth: u32,
+    evaluations: &mut usize,
+) -> Option<T>
+where
+    T: ValueType + RealValueType + Float,
+    F: Fn(T) -> T,
+{
+    if *evaluations < 2 {
+        return Some(whole);
+    }
+    *evaluations -= 2;
+
+    let two = T::new_from_real_f64(2.0);
+    let m = (a + b) / two;
+    let (lm, rm) = ((a + m) / two, (m + b) / two);
+    let (flm, frm) = (eval(lm), eval(rm));
+    let left = simpson_step(a, m, fa, flm, fm);
+    let right = simpson_step(m, b, fm, frm, fb);
+    let delta = left + right - whole;
+    if !delta.is_finite() {
+        return None;
+    }
+
+    // Richardson extrapolation, see Lyness 1969
+    let fifteen = T::new_from_real_f64(15.0);
+    if depth == 0 || delta.abs() <= fifteen * tolerance {
+        return Some(left + right + delta / fifteen);
+    }
+
+    let left = adaptive_step(
+        eval,
+        (a, m),
+        (fa, flm, fm),
+        left,
+        tolerance / two,
+        depth - 1,
+        evaluations,
+    )?;
+    let right = adaptive_step(
+        eval,
+        (m, b),
+        (fm, frm, fb),
+        right,
+        tolerance / two,
+        depth - 1,
+        evaluations,
+    )?;
+    Some(left + right)
+}
+
+/// Cumulative integral over sampled values using the trapezoidal rule.
+///
+/// The result has the same length as the input, and starts at zero.
+/// Samples do not need to be equally spaced.
+///
+/// # Panics
+/// If `x` and `y` differ in length.
+pub fn cumulative_trapezoid<X, Y>(x: &[X], y: &[Y]) -> Vec<Integral<X, Y>>
+where
+    X: QuantityType,
+    Y: QuantityType<DataType = X::DataType>,
+    Y::Dimension: Mul<X::Dimension>,
+    <Y::Dimension as Mul<X::Dimension>>::Output: Dimension,
+    Y::Kind: KindMul<X::Kind>,
+{
+    assert_eq!(x.len(), y.len(), "x and y must have the same length");
+    let two = X::DataType::new_from_real_f64(2.0);
+    let mut sum = X::DataType::ZERO;
+    let mut result = Vec::with_capacity(x.len());
+    if !x.is_empty() {
+        result.push(Quantity::new_base(sum));
+    }
+    for (xs, ys) in x.windows(2).zip(y.windows(2)) {
+        let h = xs[1].base_value() - xs[0].base_value();
+        sum = sum + h * (ys[0].base_value() + ys[1].base_value()) / two;
+        result.push(Quantity::new_base(sum));
+    }
+    result
+}
+
+/// Integral over sampled values using the trapezoidal rule. This is the last value of `cumulative_trapezoid`.
+///
+/// # Panics
+/// If `x` and `y` differ in length.
+pub fn trapezoid_sampled<X, Y>(x: &[X], y: &[Y]) -> Integral<X, Y>
+where
+    X: QuantityType,
+    Y: QuantityType<DataType = X::DataType>,
+    Y::Dimension: Mul<X::Dimension>,
+    <Y::Dimension as Mul<X::Dimension>>::Output: Dimension,
+    Y::Kind: KindMul<X::Kind>,
+{
+    cumulative_trapezoid(x, y)
+        .last()
+        .copied()
+        .unwrap_or(Quantity::new_base(X::DataType::ZERO))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::si::*;
+
+    #[test]
+    fn charge_from_current() {
+        // i(t) = 2 A/s * t, integrated over 0..3 s is 9 C
+        let current = |t: Time64| ElectricCurrent::new::<Ampere>(2.0 * t.get::<Second>());
+        let t0 = Time::new::<Second>(0.0);
+        let t1 = Time::new::<Second>(3.0);
+
+        let q: ElectricCharge64 = trapezoid(current, t0, t1, 10);
+        assert!((q.get::<Coulomb>() - 9.0).abs() < 1e-12);
+        let q: ElectricCharge64 = simpson(current, t0, t1, 3);
+        assert!((q.get::<Coulomb>() - 9.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn energy_from_power() {
+        let power = |t: Time64| Power64::new::<Watt>(t.get::<Second>().sin());
+        let t0 = Time::new::<Second>(0.0);
+        let t1 = Time::new::<Second>(std::f64::consts::PI);
+
+        let e: Energy64 = adaptive_simpson(power, t0, t1, Energy64::new::<Joule>(1e-9)).unwrap();
+        assert!((e.get::<Joule>() - 2.0).abs() < 1e-9);
+
+        let times = [0.0, 1.0, 3.0].map(Time::new::<Second>);
+        let powers = [2.0, 2.0, 4.0].map(Power64::new::<Watt>);
+        let energy = cumulative_trapezoid(&times, &powers);
+        assert_eq!(energy.len(), 3);
+        assert_eq!(energy[1].get::<Joule>(), 2.0);
+        assert_eq!(energy[2].get::<Joule>(), 8.0);
+        assert_eq!(trapezoid_sampled(&times, &powers), energy[2]);
+    }
+
+    #[test]
+    fn adaptive_limits() {
+        let t0 = Time64::new::<Second>(0.0);
+        let t1 = Time64::new::<Second>(1.0);
+
+        // NaN below 0.5 s
+        let power = |t: Time64| Power64::new::<Watt>((t.get::<Second>() - 0.5).sqrt());
+        let e: Option<Energy64> = adaptive_simpson(power, t0, t1, Energy64::new::<Joule>(1e-9));
+        assert!(e.is_none());
+        let power = |t: Time64| Power64::new::<Watt>(1.0 / (t.get::<Second>() - 0.5));
+        let e: Option<Energy64> = adaptive_simpson(power, t0, t1, Energy64::new::<Joule>(1e-9));
+        assert!(e.is_none());
+
+        // a kink that never converges is cut off by the evaluation budget
+        let calls = std::cell::Cell::new(0);
+        let power = |t: Time64| {
+            calls.set(calls.get() + 1);
+            Power64::new::<Watt>((t.get::<Second>() - 1.0 / 3.0).abs().sqrt())
+        };
+        let e: Energy64 = adaptive_simpson(power, t0, t1, Energy64::new::<Joule>(1e-300)).unwrap();
+        assert!((e.get::<Joule>() - 0.4911874).abs() < 1e-3);
+        assert!(calls.get() <= MAX_ADAPTIVE_EVALUATIONS);
+    }
+}
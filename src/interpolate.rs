@@ -0,0 +1,365 @@
+use num::{
+    traits::{
+        ConstOne,
+        ConstZero,
+    },
+    Float,
+};
+
+use crate::{
+    quantity::QuantityType,
+    value_type::{
+        RealValueType,
+        ValueType,
+    },
+};
+
+/// What an interpolator does with values outside of its table
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Extrapolation {
+    /// Use the value at the closest end of the table
+    Clamp,
+    /// Continue the first or last segment of the interpolation
+    Extend,
+    /// Return `None`
+    Forbid,
+}
+
+/// Common interface for all interpolators.
+pub trait Interpolator<X, Y> {
+    /// Interpolate the table at `x`.
+    ///
+    /// Returns `None` if `x` is outside the table and the extrapolation policy is `Extrapolation::Forbid`.
+    fn eval(&self, x: X) -> Option<Y>;
+}
+
+/// The table shared by all interpolators
+#[derive(Debug, Clone)]
+struct Table<X, Y> {
+    xs: Vec<X>,
+    ys: Vec<Y>,
+    extrapolation: Extrapolation,
+}
+
+impl<X, Y> Table<X, Y>
+where
+    X: QuantityType + Copy + PartialOrd,
+    X::DataType: RealValueType + Float,
+    Y: QuantityType<DataType = X::DataType> + Copy,
+{
+    fn new(xs: &[X], ys: &[Y], extrapolation: Extrapolation) -> Option<Self> {
+        if xs.len() != ys.len() || xs.len() < 2 || xs.windows(2).any(|w| w[0] >= w[1]) {
+            return None;
+        }
+        Some(Self {
+            xs: xs.to_vec(),
+            ys: ys.to_vec(),
+            extrapolation,
+        })
+    }
+
+    /// Find the segment to use for `x`, and the position to evaluate it at.
+    fn locate(&self, x: X) -> Option<(usize, X::DataType)> {
+        let last = self.xs.len() - 1;
+        let segment = self.xs.partition_point(|xi| *xi <= x).clamp(1, last) - 1;
+        let inside = x >= self.xs[0] && x <= self.xs[last];
+        match self.extrapolation {
+            _ if inside => Some((segment, x.base_value())),
+            Extrapolation::Clamp => Some((
+                segment,
+                x.base_value()
+                    .max(self.xs[0].base_value())
+                    .min(self.xs[last].base_value()),
+            )),
+            Extrapolation::Extend => Some((segment, x.base_value())),
+            Extrapolation::Forbid => None,
+        }
+    }
+
+    fn x(&self, i: usize) -> X::DataType {
+        self.xs[i].base_value()
+    }
+
+    fn y(&self, i: usize) -> X::DataType {
+        self.ys[i].base_value()
+    }
+
+    /// Width and slope of every segment
+    fn secants(&self) -> (Vec<X::DataType>, Vec<X::DataType>) {
+        (0..self.xs.len() - 1)
+            .map(|i| {
+                let h = self.x(i + 1) - self.x(i);
+                (h, (self.y(i + 1) - self.y(i)) / h)
+            })
+            .unzip()
+    }
+
+    /// Evaluate the cubic hermite polynomial of segment `i` at `x`, given the slopes at all knots.
+    fn hermite(&self, slopes: &[X::DataType], i: usize, x: X::DataType) -> Y {
+        let one = X::DataType::ONE;
+        let (two, three) = (
+            X::DataType::new_from_real_f64(2.0),
+            X::DataType::new_from_real_f64(3.0),
+        );
+        let h = self.x(i + 1) - self.x(i);
+        let t = (x - self.x(i)) / h;
+        let (t2, t3) = (t * t, t * t * t);
+
+        let h00 = two * t3 - three * t2 + one;
+        let h10 = t3 - two * t2 + t;
+        let h01 = three * t2 - two * t3;
+        let h11 = t3 - t2;
+        Y::from_base(
+            h00 * self.y(i) + h10 * h * slopes[i] + h01 * self.y(i + 1) + h11 * h * slopes[i + 1],
+        )
+    }
+}
+
+/// Piecewise linear interpolation
+#[derive(Debug, Clone)]
+pub struct LinearInterpolator<X, Y> {
+    table: Table<X, Y>,
+}
+
+impl<X, Y> LinearInterpolator<X, Y>
+where
+    X: QuantityType + Copy + PartialOrd,
+    X::DataType: RealValueType + Float,
+    Y: QuantityType<DataType = X::DataType> + Copy,
+{
+    /// Create a new interpolator from a table.
+    ///
+    /// Returns `None` if `xs` and `ys` differ in length, there are less than two points,
+    /// or `xs` is not strictly increasing.
+    pub fn new(xs: &[X], ys: &[Y], extrapolation: Extrapolation) -> Option<Self> {
+        Some(Self {
+            table: Table::new(xs, ys, extrapolation)?,
+        })
+    }
+}
+
+impl<X, Y> Interpolator<X, Y> for LinearInterpolator<X, Y>
+where
+    X: QuantityType + Copy + PartialOrd,
+    X::DataType: RealValueType + Float,
+    Y: QuantityType<DataType = X::DataType> + Copy,
+{
+    fn eval(&self, x: X) -> Option<Y> {
+        let (i, x) = self.table.locate(x)?;
+        let t = &self.table;
+        let slope = (t.y(i + 1) - t.y(i)) / (t.x(i + 1) - t.x(i));
+        Some(Y::from_base(t.y(i) + slope * (x - t.x(i))))
+    }
+}
+
+/// Natural cubic spline interpolation.
+///
+/// The curve is twice continuously differentiable, but may overshoot between points.
+/// If that is a problem, use `PchipInterpolator`.
+#[derive(Debug, Clone)]
+pub struct CubicSplineInterpolator<X, Y>
+where
+    X: QuantityType,
+{
+    table: Table<X, Y>,
+    slopes: Vec<X::DataType>,
+}
+
+impl<X, Y> CubicSplineInterpolator<X, Y>
+where
+    X: QuantityType + Copy + PartialOrd,
+    X::DataType: RealValueType + Float,
+    Y: QuantityType<DataType = X::DataType> + Copy,
+{
+    /// Create a new interpolator from a table.
+    ///
+    /// Returns `None` if `xs` and `ys` differ in length, there are less than two points,
+    /// or `xs` is not strictly increasing.
+    pub fn new(xs: &[X], ys: &[Y], extrapolation: Extrapolation) -> Option<Self> {
+        let table = Table::new(xs, ys, extrapolation)?;
+        let (h, delta) = table.secants();
+        let n = xs.len();
+        let (two, three) = (
+            X::DataType::new_from_real_f64(2.0),
+            X::DataType::new_from_real_f64(3.0),
+        );
+
+        // Tridiagonal system for the slopes at the knots, with natural boundary conditions
+        let mut lower = vec![X::DataType::ZERO; n];
+        let mut diag = vec![X::DataType::ZERO; n];
+        let mut upper = vec![X::DataType::ZERO; n];
+        let mut rhs = vec![X::DataType::ZERO; n];
+        diag[0] = two;
+        upper[0] = X::DataType::ONE;
+        rhs[0] = three * delta[0];
+        for i in 1..n - 1 {
+            lower[i] = h[i];
+            diag[i] = two * (h[i - 1] + h[i]);
+            upper[i] = h[i - 1];
+            rhs[i] = three * (h[i] * delta[i - 1] + h[i - 1] * delta[i]);
+        }
+        lower[n - 1] = X::DataType::ONE;
+        diag[n - 1] = two;
+        rhs[n - 1] = three * delta[n - 2];
+
+        // Thomas algorithm
+        for i in 1..n {
+            let w = lower[i] / diag[i - 1];
+            diag[i] = diag[i] - w * upper[i - 1];
+            rhs[i] = rhs[i] - w * rhs[i - 1];
+        }
+        let mut slopes = vec![X::DataType::ZERO; n];
+        slopes[n - 1] = rhs[n - 1] / diag[n - 1];
+        for i in (0..n - 1).rev() {
+            slopes[i] = (rhs[i] - upper[i] * slopes[i + 1]) / diag[i];
+        }
+
+        Some(Self { table, slopes })
+    }
+}
+
+impl<X, Y> Interpolator<X, Y> for CubicSplineInterpolator<X, Y>
+where
+    X: QuantityType + Copy + PartialOrd,
+    X::DataType: RealValueType + Float,
+    Y: QuantityType<DataType = X::DataType> + Copy,
+{
+    fn eval(&self, x: X) -> Option<Y> {
+        let (i, x) = self.table.locate(x)?;
+        Some(self.table.hermite(&self.slopes, i, x))
+    }
+}
+
+/// Monotone piecewise cubic hermite interpolation (PCHIP).
+///
+/// Unlike the cubic spline this never overshoots: between two points the curve stays monotone.
+/// That makes it a good fit for calibration tables and material properties.
+#[derive(Debug, Clone)]
+pub struct PchipInterpolator<X, Y>
+where
+    X: QuantityType,
+{
+    table: Table<X, Y>,
+    slopes: Vec<X::DataType>,
+}
+
+impl<X, Y> PchipInterpolator<X, Y>
+where
+    X: QuantityType + Copy + PartialOrd,
+    X::DataType: RealValueType + Float,
+    Y: QuantityType<DataType = X::DataType> + Copy,
+{
+    /// Create a new interpolator from a table.
+    ///
+    /// Returns `None` if `xs` and `ys` differ in length, there are less than two points,
+    /// or `xs` is not strictly increasing.
+    pub fn new(xs: &[X], ys: &[Y], extrapolation: Extrapolation) -> Option<Self> {
+        let table = Table::new(xs, ys, extrapolation)?;
+        let (h, delta) = table.secants();
+        let n = xs.len();
+        if n == 2 {
+            return Some(Self {
+                table,
+                slopes: vec![delta[0], delta[0]],
+            });
+        }
+
+        let zero = X::DataType::ZERO;
+        let (two, three) = (
+            X::DataType::new_from_real_f64(2.0),
+            X::DataType::new_from_real_f64(3.0),
+        );
+        let mut slopes = vec![zero; n];
+        for i in 1..n - 1 {
+            if delta[i - 1] * delta[i] > zero {
+                // weighted harmonic mean, Fritsch and Butland
+                let w1 = two * h[i] + h[i - 1];
+                let w2 = h[i] + two * h[i - 1];
+                slopes[i] = (w1 + w2) / (w1 / delta[i - 1] + w2 / delta[i]);
+            }
+        }
+        let end_slope = |h0: X::DataType, h1: X::DataType, d0: X::DataType, d1: X::DataType| {
+            let m = ((two * h0 + h1) * d0 - h0 * d1) / (h0 + h1);
+            if m.signum() != d0.signum() {
+                zero
+            } else if d0.signum() != d1.signum() && m.abs() > (three * d0).abs() {
+                three * d0
+            } else {
+                m
+            }
+        };
+        slopes[0] = end_slope(h[0], h[1], delta[0], delta[1]);
+        slopes[n - 1] = end_slope(h[n - 2], h[n - 3], delta[n - 2], delta[n - 3]);
+
+        Some(Self { table, slopes })
+    }
+}
+
+impl<X, Y> Interpolator<X, Y> for PchipInterpolator<X, Y>
+where
+    X: QuantityType + Copy + PartialOrd,
+    X::DataType: RealValueType + Float,
+    Y: QuantityType<DataType = X::DataType> + Copy,
+{
+    fn eval(&self, x: X) -> Option<Y> {
+        let (i, x) = self.table.locate(x)?;
+        Some(self.table.hermite(&self.slopes, i, x))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::si::*;
+
+    #[test]
+    fn linear_resistance() {
+        let temps = [0.0, 100.0].map(ThermodynamicTemperature64::new::<Celsius>);
+        let resistances = [100.0, 138.5].map(ElectricResistance64::new::<Ohm>);
+
+        let pt100 = LinearInterpolator::new(&temps, &resistances, Extrapolation::Forbid).unwrap();
+        let r = pt100
+            .eval(ThermodynamicTemperature::new::<Celsius>(50.0))
+            .unwrap();
+        assert!((r.get::<Ohm>() - 119.25).abs() < 1e-9);
+        assert!(pt100
+            .eval(ThermodynamicTemperature::new::<Celsius>(150.0))
+            .is_none());
+
+        let clamped = LinearInterpolator::new(&temps, &resistances, Extrapolation::Clamp).unwrap();
+        let r = clamped
+            .eval(ThermodynamicTemperature::new::<Celsius>(150.0))
+            .unwrap();
+        assert!((r.get::<Ohm>() - 138.5).abs() < 1e-9);
+
+        let extended =
+            LinearInterpolator::new(&temps, &resistances, Extrapolation::Extend).unwrap();
+        let r = extended
+            .eval(ThermodynamicTemperature::new::<Celsius>(200.0))
+            .unwrap();
+        assert!((r.get::<Ohm>() - 177.0).abs() < 1e-9);
+
+        assert!(LinearInterpolator::new(&temps, &resistances[..1], Extrapolation::Clamp).is_none());
+    }
+
+    #[test]
+    fn cubic_and_pchip() {
+        let xs = [0.0, 1.0, 2.0, 3.0].map(Length64::new::<Metre>);
+        let ys = [0.0, 1.0, 8.0, 27.0].map(Mass64::new::<Kilogram>);
+        let spline = CubicSplineInterpolator::new(&xs, &ys, Extrapolation::Forbid).unwrap();
+        let pchip = PchipInterpolator::new(&xs, &ys, Extrapolation::Forbid).unwrap();
+
+        for (x, y) in xs.iter().zip(ys.iter()) {
+            assert!((spline.eval(*x).unwrap() - *y).abs().get::<Kilogram>() < 1e-12);
+            assert!((pchip.eval(*x).unwrap() - *y).abs().get::<Kilogram>() < 1e-12);
+        }
+
+        // pchip stays monotone on a step
+        let ys = [0.0, 0.0, 1.0, 1.0].map(Mass64::new::<Kilogram>);
+        let pchip = PchipInterpolator::new(&xs, &ys, Extrapolation::Forbid).unwrap();
+        for i in 0..=30 {
+            let y = pchip.eval(Length::new::<Metre>(i as f64 * 0.1)).unwrap();
+            assert!(y.get::<Kilogram>() >= 0.0 && y.get::<Kilogram>() <= 1.0);
+        }
+    }
+}
@@ -1,13 +1,35 @@
+pub mod ac;
+pub mod atomic_units;
+pub mod chemistry;
 pub mod constants;
 pub mod dimension;
+pub mod fluid;
+pub mod integrate;
+pub mod interpolate;
 pub mod kind;
 pub mod macros;
+pub mod matrix;
+pub mod natural_units;
+pub mod ode;
+pub mod photometry;
+pub mod polynomial;
 pub mod quantity;
+pub mod registry;
+pub mod regression;
+pub mod roots;
 pub mod si;
+pub mod statistics;
+pub mod thermal;
+pub mod time;
 pub mod unit;
+pub mod unit_system;
+pub mod us_customary;
 pub mod util;
 pub mod value_type;
+pub mod vector;
 
+#[doc(hidden)]
+pub use inventory;
 pub use num::{
     self,
     complex::{
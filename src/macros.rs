@@ -1,6 +1,9 @@
 #[macro_export]
 macro_rules! unit {
     ($name:ident,$print_name:literal,$description:literal) => {
+        $crate::unit!($name, $print_name, $description, prefix_units = []);
+    };
+    ($name:ident,$print_name:literal,$description:literal, prefix_units = [$($pfx_commands:tt)*]) => {
         #[doc=$description]
         pub struct $name {}
 
@@ -13,6 +16,87 @@ macro_rules! unit {
                 $print_name
             }
         }
+
+        $crate::register_prefixes!($name, [$($pfx_commands)*]);
+    };
+}
+
+/// Implement `RegisterUnit` for a unit, registering its prefixed versions along with it
+#[doc(hidden)]
+#[macro_export]
+macro_rules! register_prefixes {
+    ($name:ident, []) => {
+        impl $crate::registry::RegisterUnit for $name {
+            type Stem = $name;
+        }
+    };
+    ($name:ident, [AllPrefixes]) => {
+        $crate::register_prefixes!(@prefixes $name, $name, [
+            Quetta, Ronna, Yotta, Zetta, Exa, Peta, Tera, Giga, Mega, Kilo, Hecto, Deca,
+            Deci, Centi, Milli, Micro, Nano, Pico, Femto, Atto, Zepto, Yocto, Ronto, Quecto
+        ]);
+    };
+    ($name:ident, [KgSpecialCase]) => {
+        $crate::register_prefixes!(@prefixes $name, Kilogram, [
+            Quetta, Ronna, Yotta, Zetta, Exa, Peta, Tera, Giga, Mega, Hecto, Deca,
+            Deci, Centi, Milli, Micro, Nano, Pico, Femto, Atto, Zepto, Yocto, Ronto, Quecto
+        ]);
+    };
+    (@prefixes $name:ident, $stem:ident, [$($prefix:ident),*]) => {
+        impl $crate::registry::RegisterUnit for $name {
+            type Stem = $stem;
+
+            fn register_prefixed<Q>(registry: &mut $crate::registry::UnitRegistry, quantity: &'static str)
+            where
+                Q: $crate::quantity::QuantityType<DataType = f64>,
+                Q::Kind: 'static,
+                $stem: $crate::unit::LinearConversion<f64, Q::Dimension, Q::Kind>,
+            {
+                $crate::paste!($(registry.register::<Q, [<$prefix $name>]>(quantity);)*);
+            }
+        }
+    };
+}
+
+/// Register a unit of a quantity, and its prefixed versions, with [`UnitRegistry::si`](crate::registry::UnitRegistry::si).
+///
+/// The quantity macros do this for every unit they declare. `base` marks the base unit of the quantity,
+/// optionally with its position among the quantities of the same macro call, `nonlinear` a unit without prefixed versions.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! register_unit {
+    (base $name:ident, $unit:ident) => {
+        $crate::register_unit!(@submit true, 0, $name, $unit);
+    };
+    (base $name:ident, $unit:ident, $order:expr) => {
+        $crate::register_unit!(@submit true, $order, $name, $unit);
+    };
+    ($name:ident, $unit:ident) => {
+        $crate::register_unit!(@submit false, 0, $name, $unit);
+    };
+    (nonlinear $name:ident, $unit:ident) => {
+        $crate::inventory::submit!($crate::registry::Registration::new(
+            file!(),
+            line!(),
+            0,
+            false,
+            |registry| registry.register::<$name<f64>, $unit>(stringify!($name)),
+        ));
+    };
+    (@submit $base:literal, $order:expr, $name:ident, $unit:ident) => {
+        $crate::inventory::submit!($crate::registry::Registration::new(
+            file!(),
+            line!(),
+            $order,
+            $base,
+            |registry| {
+                registry.register::<$name<f64>, $unit>(stringify!($name));
+                <$unit as $crate::registry::RegisterUnit>::register_prefixed::<$name<f64>>(
+                    registry,
+                    stringify!($name),
+                );
+            },
+        ));
     };
 }
 
@@ -171,7 +255,7 @@ macro_rules! make_unit_prefixes {
 
             impl $crate::unit::Unit for [<$pfx_name $long_name>] {
                 fn long_name() -> &'static str {
-                    stringify!($pfx_name, $long_name)
+                    concat!(stringify!($pfx_name), stringify!($long_name))
                 }
 
                 fn print_name() -> &'static str {
@@ -196,7 +280,7 @@ macro_rules! make_units {
             $($long_name:ident: $print_name:literal,$description:literal$(,prefix_units=[$($pfx_commands:tt)*])*;)*
         ) => {
             $(
-                $crate::unit!($long_name, $print_name, $description);
+                $crate::unit!($long_name, $print_name, $description, prefix_units = [$($($pfx_commands)*)*]);
                 $(
                     $crate::make_unit_prefixes!([$($pfx_commands,)*], $long_name,$print_name, $description);
                 )*
@@ -212,6 +296,21 @@ macro_rules! make_quantity {
         dimension: $dim:tt,
         base_unit: $base:ident,
         use_kind: $kind:ty $(,)*
+    ) => {
+        $crate::make_quantity!(
+            @order 0,
+            name: $name,
+            dimension: $dim,
+            base_unit: $base,
+            use_kind: $kind,
+        );
+    };
+    (
+        @order $order:expr,
+        name: $name:ident,
+        dimension: $dim:tt,
+        base_unit: $base:ident,
+        use_kind: $kind:ty $(,)*
     ) => {
         pub type $name<T> = $crate::quantity::Quantity<T,$dim,$kind>;
         $crate::paste!(
@@ -220,13 +319,8 @@ macro_rules! make_quantity {
             pub type [<$name C32>] = $name<$crate::num::Complex<f32>>;
             pub type [<$name C64>] = $name<$crate::num::Complex<f64>>;
         );
-        impl<T> $crate::unit::QuantityConversion<T,$dim,$kind> for $base
-        where T: $crate::value_type::ValueType
-        {
-            fn factor() -> T {
-                T::new_from_real_f64(1.0)
-            }
-        }
+        $crate::linear_unit!($base, $dim, $kind, 1.0);
+        $crate::register_unit!(base $name, $base, $order);
     };
     (
         name: $name:ident,
@@ -260,40 +354,58 @@ macro_rules! make_quantity {
 
 #[macro_export]
 macro_rules! make_base_quantities {
-    ($(
-        $name:ident: $dim:ident, $unit:ident;
-    )*
-    ) => {
-        $(
-            $crate::make_quantity!(
-                name: $name,
-                dimension: $dim,
-                base_unit: $unit,
-            );
-        )*
+    // The quantities are numbered, so the registry keeps them in the order they are declared in
+    (@order ($($order:tt)*)) => {};
+    (@order ($($order:tt)*) $name:ident: $dim:ident, $unit:ident; $($rest:tt)*) => {
+        $crate::make_quantity!(
+            @order $($order)*,
+            name: $name,
+            dimension: $dim,
+            base_unit: $unit,
+            use_kind: (),
+        );
+        $crate::make_base_quantities!(@order (1 + $($order)*) $($rest)*);
+    };
+    ($($quantities:tt)*) => {
+        $crate::make_base_quantities!(@order (0) $($quantities)*);
     };
 }
 
 #[macro_export]
 macro_rules! type_math {
-    ($a:tt) => {$a<T>};
-    ($a:tt * $($b:tt)+) => {
-        typenum::Prod<$a<T>,$crate::type_math!($($b)*)>
+    // Operators are applied left to right, so `Length / Time / Time` is `(Length / Time) / Time`
+    (@acc ($($acc:tt)*)) => {$($acc)*};
+    (@acc ($($acc:tt)*) * $b:tt $($rest:tt)*) => {
+        $crate::type_math!(@acc ($crate::typenum::Prod<$($acc)*,$b<T>>) $($rest)*)
+    };
+    (@acc ($($acc:tt)*) / $b:tt $($rest:tt)*) => {
+        $crate::type_math!(@acc ($crate::typenum::Quot<$($acc)*,$b<T>>) $($rest)*)
     };
-    ($a:tt / $($b:tt)+) => {
-        typenum::Quot<$a<T>,$crate::type_math!($($b)*)>
+    ($a:tt $($rest:tt)*) => {
+        $crate::type_math!(@acc ($a<T>) $($rest)*)
     };
 }
 
 #[macro_export]
 macro_rules! additional_unit {
     ($name:ident, $unit:ident, $factor:expr $(,$offset:expr)*) => {
-        impl<T>
-            $crate::unit::QuantityConversion<
-                T,
-                <$name<T> as $crate::quantity::QuantityType>::Dimension,
-                <$name<T> as $crate::quantity::QuantityType>::Kind,
-            > for $unit
+        $crate::linear_unit!(
+            $unit,
+            <$name<T> as $crate::quantity::QuantityType>::Dimension,
+            <$name<T> as $crate::quantity::QuantityType>::Kind,
+            $factor
+            $(,$offset)*
+        );
+        $crate::register_unit!($name, $unit);
+    };
+}
+
+/// Implement `LinearConversion` and `QuantityConversion` for a unit of the dimension and kind,
+/// which may refer to the data type `T`: `linear_unit!(Unit, Dim, Kind, factor[, offset]);`
+#[macro_export]
+macro_rules! linear_unit {
+    ($unit:ident, $dim:ty, $kind:ty, $factor:expr $(,$offset:expr)*) => {
+        impl<T> $crate::unit::LinearConversion<T, $dim, $kind> for $unit
         where
             T: $crate::value_type::ValueType,
         {
@@ -306,6 +418,50 @@ macro_rules! additional_unit {
                 }
             )*
         }
+
+        impl<T> $crate::unit::QuantityConversion<T, $dim, $kind> for $unit
+        where
+            T: $crate::value_type::ValueType,
+        {
+            fn linear() -> Option<(T, T)> {
+                Some((
+                    <Self as $crate::unit::LinearConversion<T, $dim, $kind>>::factor(),
+                    <Self as $crate::unit::LinearConversion<T, $dim, $kind>>::offset(),
+                ))
+            }
+        }
+    };
+}
+
+/// Add a unit with arbitrary conversion functions to a quantity, for reciprocal or logarithmic units.
+///
+/// The functions work on `f64` values, so the unit is only available for real data types:
+/// `nonlinear_unit!(Quantity, Unit, |value| to_base, |base| from_base);`
+///
+/// Non-linear units have no factor, so there are no prefixed versions of them.
+#[macro_export]
+macro_rules! nonlinear_unit {
+    ($name:ident, $unit:ident, $to_base:expr, $from_base:expr $(,)*) => {
+        impl<T>
+            $crate::unit::QuantityConversion<
+                T,
+                <$name<T> as $crate::quantity::QuantityType>::Dimension,
+                <$name<T> as $crate::quantity::QuantityType>::Kind,
+            > for $unit
+        where
+            T: $crate::value_type::ValueType + $crate::value_type::RealValueType,
+        {
+            fn convert_from_base(base_value: T) -> T {
+                let from_base: fn(f64) -> f64 = $from_base;
+                T::new_from_real_f64(from_base(base_value.to_f64_mag()))
+            }
+
+            fn convert_to_base(my_value: T) -> T {
+                let to_base: fn(f64) -> f64 = $to_base;
+                T::new_from_real_f64(to_base(my_value.to_f64_mag()))
+            }
+        }
+        $crate::register_unit!(nonlinear $name, $unit);
     };
 }
 
@@ -315,6 +471,7 @@ macro_rules! derive_quantity {
         name: $name:ident,
         quantity_type: ($($ty:tt)*),
         unit: $unit:ident,
+        $(order: $order:expr,)?
         $(additional_units: [
             $($extra_unit_name:ident: $extra_unit_factor:expr $(, $extra_unit_offset:expr)*;)*
         ]$(,)*)*
@@ -326,13 +483,13 @@ macro_rules! derive_quantity {
             pub type [<$name C32>] = $name<$crate::num::Complex<f32>>;
             pub type [<$name C64>] = $name<$crate::num::Complex<f64>>;
         );
-        impl<T> $crate::unit::QuantityConversion<T,<$name<T> as $crate::quantity::QuantityType>::Dimension,<$name<T> as $crate::quantity::QuantityType>::Kind> for $unit
-        where T: $crate::value_type::ValueType
-        {
-            fn factor() -> T {
-                T::new_from_real_f64(1.0)
-            }
-        }
+        $crate::linear_unit!(
+            $unit,
+            <$name<T> as $crate::quantity::QuantityType>::Dimension,
+            <$name<T> as $crate::quantity::QuantityType>::Kind,
+            1.0
+        );
+        $crate::register_unit!(base $name, $unit, 0 $(+ $order)?);
         $($(
             $crate::additional_unit!($name, $extra_unit_name, $extra_unit_factor $(,$extra_unit_offset)*);
         )*)*
@@ -341,19 +498,24 @@ macro_rules! derive_quantity {
 
 #[macro_export]
 macro_rules! derive_quantities {
-    ($($name:ident: ($($ty:tt)*), $unit:ident $(,[$($extra_unit_name:ident: $extra_unit_factor:expr $(,$extra_unit_offset:expr)*;)*])*;)*) => {
-        $(
-            $crate::derive_quantity!(
-                name: $name,
-                quantity_type: ($($ty)*),
-                unit: $unit,
-                $(
-                additional_units: [$(
-                  $extra_unit_name: $extra_unit_factor $(, $extra_unit_offset)*;
-                )*]
-                )*
-            );
-        )*
+    // The quantities are numbered, so the registry keeps them in the order they are declared in
+    (@order ($($order:tt)*)) => {};
+    (@order ($($order:tt)*) $name:ident: ($($ty:tt)*), $unit:ident $(,[$($extra_unit_name:ident: $extra_unit_factor:expr $(,$extra_unit_offset:expr)*;)*])*; $($rest:tt)*) => {
+        $crate::derive_quantity!(
+            name: $name,
+            quantity_type: ($($ty)*),
+            unit: $unit,
+            order: $($order)*,
+            $(
+            additional_units: [$(
+              $extra_unit_name: $extra_unit_factor $(, $extra_unit_offset)*;
+            )*]
+            )*
+        );
+        $crate::derive_quantities!(@order (1 + $($order)*) $($rest)*);
+    };
+    ($($quantities:tt)*) => {
+        $crate::derive_quantities!(@order (0) $($quantities)*);
     };
 }
 
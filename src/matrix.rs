@@ -0,0 +1,449 @@
+//! Small dense matrices of quantities.
+//!
+//! All entries of a [`QMatrix`] share one dimension and kind. Systems that mix dimensions per row or column,
+//! like forces and moments in one stiffness matrix, have to be split up or brought to a common dimension first.
+
+use std::{
+    array,
+    ops::{
+        Add,
+        Div,
+        Mul,
+        Sub,
+    },
+};
+
+use crate::{
+    dimension::{
+        DimInverse,
+        Dimension,
+        NoDim,
+    },
+    kind::{
+        KindAdd,
+        KindDiv,
+        KindMul,
+        KindSub,
+    },
+    quantity::{
+        Quantity,
+        QuantityType,
+    },
+    value_type::ValueType,
+    vector::QVec,
+};
+
+/// A small dense matrix of quantities that all share the same dimension and kind.
+///
+/// Think of stiffness or conductance matrices. Multiplying with a `QVec` follows the usual dimension rules,
+/// so a stiffness matrix in N/m times a displacement vector in m yields a force vector.
+/// `solve` goes the other way around: `K x = F` with `K` in N/m and `F` in N yields `x` in m.
+#[derive(Debug)]
+pub struct QMatrix<T, const R: usize, const C: usize, Dim, K>
+where
+    T: ValueType,
+    Dim: Dimension,
+{
+    rows: [[Quantity<T, Dim, K>; C]; R],
+}
+
+impl<T, const R: usize, const C: usize, Dim, K> QMatrix<T, R, C, Dim, K>
+where
+    T: ValueType,
+    Dim: Dimension,
+{
+    /// Create a new matrix from its rows
+    pub const fn new(rows: [[Quantity<T, Dim, K>; C]; R]) -> Self {
+        Self { rows }
+    }
+
+    /// Create a new matrix from rows given in base units
+    pub fn new_base(values: [[T; C]; R]) -> Self {
+        Self {
+            rows: values.map(|row| row.map(Quantity::new_base)),
+        }
+    }
+
+    /// The matrix with all entries zero
+    pub fn zero() -> Self {
+        Self::new_base([[T::ZERO; C]; R])
+    }
+
+    /// Access a single entry
+    pub fn get(&self, row: usize, column: usize) -> Quantity<T, Dim, K> {
+        self.rows[row][column]
+    }
+
+    /// Replace a single entry
+    pub fn set(&mut self, row: usize, column: usize, value: Quantity<T, Dim, K>) {
+        self.rows[row][column] = value;
+    }
+
+    /// Get a row as vector
+    pub fn row(&self, row: usize) -> QVec<T, C, Dim, K> {
+        QVec::new(self.rows[row])
+    }
+
+    /// Get a column as vector
+    pub fn column(&self, column: usize) -> QVec<T, R, Dim, K> {
+        QVec::new(array::from_fn(|r| self.rows[r][column]))
+    }
+
+    /// The transposed matrix
+    pub fn transpose(&self) -> QMatrix<T, C, R, Dim, K> {
+        QMatrix::new(array::from_fn(|c| array::from_fn(|r| self.rows[r][c])))
+    }
+
+    fn base_values(&self) -> [[T; C]; R] {
+        self.rows.map(|row| row.map(|v| v.base_value()))
+    }
+}
+
+impl<T, const N: usize> QMatrix<T, N, N, NoDim, ()>
+where
+    T: ValueType,
+{
+    /// The unitless identity matrix
+    pub fn identity() -> Self {
+        Self::new_base(array::from_fn(|r| {
+            array::from_fn(|c| if r == c { T::ONE } else { T::ZERO })
+        }))
+    }
+}
+
+impl<T, const N: usize, Dim, K> QMatrix<T, N, N, Dim, K>
+where
+    T: ValueType,
+    Dim: Dimension,
+{
+    /// Solve `self * x = rhs` for `x`, using gaussian elimination with partial pivoting.
+    ///
+    /// The dimension of `x` is the one of `rhs` divided by the one of the matrix.
+    /// Returns `None` if the matrix is singular.
+    #[allow(clippy::type_complexity)]
+    pub fn solve<DimRhs, KRhs>(
+        &self,
+        rhs: QVec<T, N, DimRhs, KRhs>,
+    ) -> Option<QVec<T, N, <DimRhs as Div<Dim>>::Output, <KRhs as KindDiv<K>>::Output>>
+    where
+        DimRhs: Dimension + Div<Dim>,
+        KRhs: KindDiv<K>,
+        <DimRhs as Div<Dim>>::Output: Dimension,
+    {
+        let b = rhs.components().map(|v| [v.base_value()]);
+        let x = solve_base(self.base_values(), b)?;
+        Some(QVec::new_base(x.map(|row| row[0])))
+    }
+
+    /// The inverse of this matrix. Returns `None` if the matrix is singular.
+    #[allow(clippy::type_complexity)]
+    pub fn inverse(
+        &self,
+    ) -> Option<QMatrix<T, N, N, <Dim as DimInverse>::Output, <() as KindDiv<K>>::Output>>
+    where
+        Dim: DimInverse,
+        <Dim as DimInverse>::Output: Dimension,
+        (): KindDiv<K>,
+    {
+        let identity = QMatrix::<T, N, N, NoDim, ()>::identity().base_values();
+        Some(QMatrix::new_base(solve_base(self.base_values(), identity)?))
+    }
+}
+
+/// The machine epsilon of `T`, the distance from one to the next larger value
+fn epsilon<T>() -> f64
+where
+    T: ValueType,
+{
+    let mut eps = 1.0;
+    while (T::ONE + T::new_from_real_f64(eps / 2.0)).to_f64_mag() != 1.0 {
+        eps /= 2.0;
+    }
+    eps
+}
+
+/// Solves `a * x = b` for any number of right hand sides.
+fn solve_base<T, const N: usize, const M: usize>(
+    mut a: [[T; N]; N],
+    mut b: [[T; M]; N],
+) -> Option<[[T; M]; N]>
+where
+    T: ValueType,
+{
+    solve_rows(&mut a, &mut b).then_some(b)
+}
+
+/// Solves `a * x = b` in place for any number of right hand sides, leaving `x` in `b`.
+///
+/// Pivots are chosen by their magnitude relative to the largest value of their row,
+/// so this works for complex matrices and for badly scaled matrices like diag(1e10, 1e-10).
+/// Returns `false` if the matrix is singular, that is a pivot is below `eps * N` relative to its row.
+pub(crate) fn solve_rows<T, A, B>(a: &mut [A], b: &mut [B]) -> bool
+where
+    T: ValueType,
+    A: AsRef<[T]> + AsMut<[T]>,
+    B: AsRef<[T]> + AsMut<[T]>,
+{
+    let n = a.len();
+    let mut scales: Vec<f64> = a
+        .iter()
+        .map(|row| {
+            row.as_ref()
+                .iter()
+                .map(|value| value.to_f64_mag().abs())
+                .fold(0.0, f64::max)
+        })
+        .collect();
+    let tolerance = epsilon::<T>() * n as f64;
+    for col in 0..n {
+        let relative = |row: usize| a[row].as_ref()[col].to_f64_mag().abs() / scales[row];
+        let Some(pivot) = (col..n).max_by(|&i, &j| relative(i).total_cmp(&relative(j))) else {
+            return false;
+        };
+        // rows of zeros give NaN
+        if relative(pivot).is_nan() || relative(pivot) <= tolerance {
+            return false;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        scales.swap(col, pivot);
+
+        let (a_done, a_rest) = a.split_at_mut(col + 1);
+        let (b_done, b_rest) = b.split_at_mut(col + 1);
+        let pivot_row = a_done[col].as_ref();
+        let pivot_rhs = b_done[col].as_ref();
+        for (row, rhs) in a_rest.iter_mut().zip(b_rest.iter_mut()) {
+            let row = row.as_mut();
+            let factor = row[col] / pivot_row[col];
+            for (value, pivot_value) in row.iter_mut().zip(pivot_row.iter()).skip(col) {
+                *value = *value - factor * *pivot_value;
+            }
+            for (value, pivot_value) in rhs.as_mut().iter_mut().zip(pivot_rhs.iter()) {
+                *value = *value - factor * *pivot_value;
+            }
+        }
+    }
+
+    for row in (0..n).rev() {
+        let (b_open, b_solved) = b.split_at_mut(row + 1);
+        let coefficients = a[row].as_ref();
+        for (k, value) in b_open[row].as_mut().iter_mut().enumerate() {
+            let sum = b_solved
+                .iter()
+                .zip(coefficients.iter().skip(row + 1))
+                .fold(*value, |acc, (solved, &coefficient)| {
+                    acc - coefficient * solved.as_ref()[k]
+                });
+            *value = sum / coefficients[row];
+        }
+    }
+    true
+}
+
+impl<T, const R: usize, const C: usize, Dim, K> Clone for QMatrix<T, R, C, Dim, K>
+where
+    T: ValueType,
+    Dim: Dimension,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const R: usize, const C: usize, Dim, K> Copy for QMatrix<T, R, C, Dim, K>
+where
+    T: ValueType,
+    Dim: Dimension,
+{
+}
+
+impl<T, const R: usize, const C: usize, Dim, K> PartialEq for QMatrix<T, R, C, Dim, K>
+where
+    T: ValueType + PartialEq,
+    Dim: Dimension,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.rows == other.rows
+    }
+}
+
+impl<T, const R: usize, const C: usize, Dim, K1, K2> Add<QMatrix<T, R, C, Dim, K2>>
+    for QMatrix<T, R, C, Dim, K1>
+where
+    T: ValueType,
+    Dim: Dimension,
+    K1: KindAdd<K2>,
+{
+    type Output = QMatrix<T, R, C, Dim, <K1 as KindAdd<K2>>::Output>;
+
+    fn add(self, rhs: QMatrix<T, R, C, Dim, K2>) -> Self::Output {
+        let a = self.base_values();
+        let b = rhs.base_values();
+        QMatrix::new_base(array::from_fn(|r| array::from_fn(|c| a[r][c] + b[r][c])))
+    }
+}
+
+impl<T, const R: usize, const C: usize, Dim, K1, K2> Sub<QMatrix<T, R, C, Dim, K2>>
+    for QMatrix<T, R, C, Dim, K1>
+where
+    T: ValueType,
+    Dim: Dimension,
+    K1: KindSub<K2>,
+{
+    type Output = QMatrix<T, R, C, Dim, <K1 as KindSub<K2>>::Output>;
+
+    fn sub(self, rhs: QMatrix<T, R, C, Dim, K2>) -> Self::Output {
+        let a = self.base_values();
+        let b = rhs.base_values();
+        QMatrix::new_base(array::from_fn(|r| array::from_fn(|c| a[r][c] - b[r][c])))
+    }
+}
+
+/// Matrix times vector yields a vector of the product dimension
+impl<T, const R: usize, const C: usize, DimLhs, KLhs, DimRhs, KRhs> Mul<QVec<T, C, DimRhs, KRhs>>
+    for QMatrix<T, R, C, DimLhs, KLhs>
+where
+    T: ValueType,
+    DimLhs: Dimension + Mul<DimRhs>,
+    DimRhs: Dimension,
+    KLhs: KindMul<KRhs>,
+    <DimLhs as Mul<DimRhs>>::Output: Dimension,
+{
+    type Output = QVec<T, R, <DimLhs as Mul<DimRhs>>::Output, <KLhs as KindMul<KRhs>>::Output>;
+
+    fn mul(self, rhs: QVec<T, C, DimRhs, KRhs>) -> Self::Output {
+        let a = self.base_values();
+        let b = rhs.components().map(|v| v.base_value());
+        QVec::new_base(array::from_fn(|r| {
+            a[r].iter()
+                .zip(b.iter())
+                .fold(T::ZERO, |acc, (&x, &y)| acc + x * y)
+        }))
+    }
+}
+
+/// Matrix times matrix yields a matrix of the product dimension
+impl<T, const R: usize, const N: usize, const C: usize, DimLhs, KLhs, DimRhs, KRhs>
+    Mul<QMatrix<T, N, C, DimRhs, KRhs>> for QMatrix<T, R, N, DimLhs, KLhs>
+where
+    T: ValueType,
+    DimLhs: Dimension + Mul<DimRhs>,
+    DimRhs: Dimension,
+    KLhs: KindMul<KRhs>,
+    <DimLhs as Mul<DimRhs>>::Output: Dimension,
+{
+    type Output =
+        QMatrix<T, R, C, <DimLhs as Mul<DimRhs>>::Output, <KLhs as KindMul<KRhs>>::Output>;
+
+    fn mul(self, rhs: QMatrix<T, N, C, DimRhs, KRhs>) -> Self::Output {
+        let a = self.base_values();
+        let b = rhs.base_values();
+        QMatrix::new_base(array::from_fn(|r| {
+            array::from_fn(|c| (0..N).fold(T::ZERO, |acc, k| acc + a[r][k] * b[k][c]))
+        }))
+    }
+}
+
+/// Scaling a matrix by a quantity yields a matrix of the product dimension
+impl<T, const R: usize, const C: usize, DimLhs, KLhs, DimRhs, KRhs> Mul<Quantity<T, DimRhs, KRhs>>
+    for QMatrix<T, R, C, DimLhs, KLhs>
+where
+    T: ValueType,
+    DimLhs: Dimension + Mul<DimRhs>,
+    DimRhs: Dimension,
+    KLhs: KindMul<KRhs>,
+    <DimLhs as Mul<DimRhs>>::Output: Dimension,
+{
+    type Output =
+        QMatrix<T, R, C, <DimLhs as Mul<DimRhs>>::Output, <KLhs as KindMul<KRhs>>::Output>;
+
+    fn mul(self, rhs: Quantity<T, DimRhs, KRhs>) -> Self::Output {
+        QMatrix::new_base(
+            self.base_values()
+                .map(|row| row.map(|v| v * rhs.base_value())),
+        )
+    }
+}
+
+impl<T, const R: usize, const C: usize, Dim, K> Mul<T> for QMatrix<T, R, C, Dim, K>
+where
+    T: ValueType,
+    Dim: Dimension,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        QMatrix::new_base(self.base_values().map(|row| row.map(|v| v * rhs)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::si::*;
+
+    #[test]
+    fn stiffness_solve() {
+        // two springs in series, fixed at one end, both 100 N/m
+        let k = QMatrix::<f64, 2, 2, _, _>::new([
+            [
+                SurfaceTension64::new::<NewtonPerMetre>(200.0),
+                SurfaceTension64::new::<NewtonPerMetre>(-100.0),
+            ],
+            [
+                SurfaceTension64::new::<NewtonPerMetre>(-100.0),
+                SurfaceTension64::new::<NewtonPerMetre>(100.0),
+            ],
+        ]);
+        let f =
+            QVec::<f64, 2, _, _>::new([Force64::new::<Newton>(0.0), Force64::new::<Newton>(10.0)]);
+
+        let x = k.solve(f).expect("matrix is not singular");
+        let displacement: Length64 = x[1];
+        assert!((displacement.get::<Metre>() - 0.2).abs() < 1e-12);
+        assert!((x[0].get::<MilliMetre>() - 100.0).abs() < 1e-9);
+
+        let f2: QVec<f64, 2, _, ()> = k * x;
+        assert!((f2[1].get::<Newton>() - 10.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn inverse_and_singular() {
+        let g = QMatrix::<f64, 2, 2, _, _>::new_base([[2.0, 0.0], [0.0, 4.0]]);
+        let g: QMatrix<f64, 2, 2, <ElectricConductivity64 as QuantityType>::Dimension, ()> = g;
+        let r = g.inverse().expect("matrix is not singular");
+        let r00: ElectricResistance64 = r.get(0, 0);
+        assert_eq!(r00.get::<Ohm>(), 0.5);
+        assert_eq!(r.get(1, 1).get::<Ohm>(), 0.25);
+
+        let singular = QMatrix::<f64, 2, 2, NoDim, ()>::new_base([[1.0, 2.0], [2.0, 4.0]]);
+        assert!(singular.inverse().is_none());
+        // rank deficient, but rounding leaves a tiny non-zero pivot
+        let rank_two = QMatrix::<f64, 3, 3, NoDim, ()>::new_base([
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0],
+        ]);
+        assert!(rank_two.inverse().is_none());
+        let rank_two = QMatrix::<f32, 3, 3, NoDim, ()>::new_base([
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0],
+        ]);
+        assert!(rank_two.inverse().is_none());
+        // the tolerance is relative, so small but regular matrices still invert
+        let small = QMatrix::<f64, 2, 2, NoDim, ()>::new_base([[2e-20, 0.0], [0.0, 4e-20]]);
+        assert_eq!(small.inverse().unwrap().get(0, 0).get::<Ratio>(), 0.5e20);
+        // soft and stiff elements in one matrix are badly scaled, but regular
+        let mixed = QMatrix::<f64, 2, 2, NoDim, ()>::new_base([[1e10, 0.0], [0.0, 1e-10]]);
+        let inverse = mixed.inverse().unwrap();
+        assert_eq!(inverse.get(0, 0).get::<Ratio>(), 1e-10);
+        assert_eq!(inverse.get(1, 1).get::<Ratio>(), 1e10);
+        let zero_row = QMatrix::<f64, 2, 2, NoDim, ()>::new_base([[1.0, 2.0], [0.0, 0.0]]);
+        assert!(zero_row.inverse().is_none());
+        assert_eq!(
+            (QMatrix::<f64, 2, 2, NoDim, ()>::identity() * g).get(1, 1),
+            g.get(1, 1)
+        );
+    }
+}
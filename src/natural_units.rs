@@ -0,0 +1,145 @@
+//! Natural units, with ħ = c = 1.
+//!
+//! Every quantity built from length, mass and time becomes a power of energy:
+//! mass is `E / c²`, length is `ħc / E` and time is `ħ / E`.
+//! `NaturalUnit<E>` is a unit for all of them, with `E` the energy unit to measure in:
+//! ```
+//! # use silib::{natural_units::NaturalUnit, quantity::QuantityType, si::*};
+//! let proton = Mass64::new::<NaturalUnit<MegaElectronvolt>>(938.272_088_16);
+//! assert!((proton.get::<Kilogram>() - 1.672_621_923_69e-27).abs() < 1e-36);
+//!
+//! // lengths are measured in GeV⁻¹
+//! let length = Length64::new::<NaturalUnit<GigaElectronvolt>>(1.0);
+//! assert!((length.get::<FemtoMetre>() - 0.197_326_98).abs() < 1e-8);
+//! ```
+
+use std::{
+    borrow::Cow,
+    marker::PhantomData,
+};
+
+use typenum::{
+    Integer,
+    Z0,
+};
+
+use crate::{
+    constants::{
+        HBAR,
+        SPEED_OF_LIGHT,
+    },
+    dimension::{
+        Dimension,
+        SiDim,
+    },
+    quantity::QuantityType,
+    si::Energy64,
+    unit::{
+        LinearConversion,
+        QuantityConversion,
+        Unit,
+    },
+    util::superscript,
+    value_type::ValueType,
+};
+
+type EnergyDim = <Energy64 as QuantityType>::Dimension;
+
+/// Natural units with ħ = c = 1, measured in powers of the energy unit `E`
+pub struct NaturalUnit<E> {
+    _energy: PhantomData<E>,
+}
+
+impl<E> Unit for NaturalUnit<E>
+where
+    E: Unit,
+{
+    fn long_name() -> &'static str {
+        "NaturalUnit"
+    }
+
+    /// The symbol of the energy unit. Formatted quantities use the power of it that matches their dimension.
+    fn print_name() -> &'static str {
+        E::print_name()
+    }
+
+    /// The energy symbol with the exponent of the dimension, like GeV⁻¹ for lengths
+    fn symbol<Dim>() -> Cow<'static, str>
+    where
+        Dim: Dimension,
+    {
+        let [l, m, t, ..] = Dim::EXPONENTS;
+        match m - l - t {
+            0 => Cow::Borrowed(""),
+            1 => Cow::Borrowed(E::print_name()),
+            exponent => Cow::Owned(format!("{}{}", E::print_name(), superscript(exponent))),
+        }
+    }
+}
+
+/// The factor of `NaturalUnit<E>` for the dimension L^l M^m T^t
+fn factor<L, M, Ti, E>() -> f64
+where
+    L: Integer,
+    M: Integer,
+    Ti: Integer,
+    E: LinearConversion<f64, EnergyDim, ()>,
+{
+    // L^l M^m T^t = E^(m-l-t) ħ^(l+t) c^(l-2m)
+    let (l, m, t) = (L::to_i32(), M::to_i32(), Ti::to_i32());
+    let energy = E::factor().powi(m - l - t);
+    let hbar = HBAR.base_value().powi(l + t);
+    let c = SPEED_OF_LIGHT.base_value().powi(l - 2 * m);
+    energy * hbar * c
+}
+
+impl<T, L, M, Ti, K, E> QuantityConversion<T, SiDim<L, M, Ti, Z0, Z0, Z0, Z0>, K> for NaturalUnit<E>
+where
+    T: ValueType,
+    L: Integer,
+    M: Integer,
+    Ti: Integer,
+    SiDim<L, M, Ti, Z0, Z0, Z0, Z0>: Dimension,
+    E: Unit + LinearConversion<f64, EnergyDim, ()>,
+{
+    fn linear() -> Option<(T, T)> {
+        Some((T::new_from_real_f64(factor::<L, M, Ti, E>()), T::ZERO))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::si::*;
+
+    #[test]
+    fn particle_physics() {
+        let energy = Energy64::new::<NaturalUnit<GigaElectronvolt>>(1.0);
+        assert!((energy.get::<Electronvolt>() - 1e9).abs() < 1e-6);
+
+        let time = Time64::new::<NaturalUnit<GigaElectronvolt>>(1.0);
+        assert!((time.get::<Second>() - 6.582_119_569e-25).abs() < 1e-33);
+
+        let velocity = Velocity64::new::<MetresPerSecond>(299_792_458.0 / 2.0);
+        assert!((velocity.get::<NaturalUnit<GigaElectronvolt>>() - 0.5).abs() < 1e-15);
+
+        let momentum = Momentum64::new::<NaturalUnit<MegaElectronvolt>>(100.0);
+        let back = momentum.get::<NaturalUnit<MegaElectronvolt>>();
+        assert!((back - 100.0).abs() < 1e-12);
+
+        let length = Length64::new::<NaturalUnit<GigaElectronvolt>>(2.0);
+        let area = length * length;
+        assert_eq!(
+            format!("{:.1}", length.formatted::<NaturalUnit<GigaElectronvolt>>()),
+            "2.0 GeV⁻¹"
+        );
+        assert_eq!(
+            format!("{:.1}", area.formatted::<NaturalUnit<GigaElectronvolt>>()),
+            "4.0 GeV⁻²"
+        );
+        assert_eq!(
+            format!("{}", energy.formatted::<NaturalUnit<GigaElectronvolt>>()),
+            "1 GeV"
+        );
+    }
+}
@@ -0,0 +1,410 @@
+use std::ops::Div;
+
+use num::Float;
+
+use crate::{
+    dimension::{
+        Dimension,
+        TimeDim,
+    },
+    kind::KindDiv,
+    quantity::{
+        Quantity,
+        QuantityType,
+    },
+    si::Time,
+    value_type::{
+        RealValueType,
+        ValueType,
+    },
+};
+
+/// Maximum number of steps `rk45` takes before giving up
+const MAX_ADAPTIVE_STEPS: usize = 1_000_000;
+
+/// A state that can be stepped through time by the solvers in this module.
+///
+/// This is implemented for quantities, tuples of states and arrays of states.
+/// For your own structs, implement it by forwarding to the members.
+pub trait OdeState<T>: Copy
+where
+    T: ValueType,
+{
+    /// The time derivative of this state. For a `Length` this is a `Velocity`.
+    type Derivative: Copy;
+
+    /// Returns `self + derivative * dt`
+    fn advance(self, derivative: Self::Derivative, dt: Time<T>) -> Self;
+
+    /// The largest component wise difference between `self` and `other`, scaled by the allowed error.
+    ///
+    /// The allowed error of a component is `abs_tolerance + rel_tolerance * |self|`.
+    /// A value below 1.0 means the difference is within tolerance.
+    fn error_norm(self, other: Self, abs_tolerance: Self, rel_tolerance: f64) -> f64;
+}
+
+impl<T, D, K> OdeState<T> for Quantity<T, D, K>
+where
+    T: ValueType,
+    D: Dimension + Div<TimeDim>,
+    <D as Div<TimeDim>>::Output: Dimension,
+    K: KindDiv<()>,
+{
+    type Derivative = Quantity<T, <D as Div<TimeDim>>::Output, <K as KindDiv<()>>::Output>;
+
+    fn advance(self, derivative: Self::Derivative, dt: Time<T>) -> Self {
+        Self::new_base(self.base_value() + derivative.base_value() * dt.base_value())
+    }
+
+    fn error_norm(self, other: Self, abs_tolerance: Self, rel_tolerance: f64) -> f64 {
+        let difference = (self.base_value() - other.base_value()).to_f64_mag();
+        let scale = abs_tolerance.base_value().to_f64_mag().abs()
+            + rel_tolerance * self.base_value().to_f64_mag().abs();
+        difference.abs() / scale
+    }
+}
+
+macro_rules! tuple_state {
+    ($($name:ident: $index:tt),*) => {
+        impl<T, $($name),*> OdeState<T> for ($($name,)*)
+        where
+            T: ValueType,
+            $($name: OdeState<T>,)*
+        {
+            type Derivative = ($($name::Derivative,)*);
+
+            fn advance(self, derivative: Self::Derivative, dt: Time<T>) -> Self {
+                ($(self.$index.advance(derivative.$index, dt),)*)
+            }
+
+            fn error_norm(self, other: Self, abs_tolerance: Self, rel_tolerance: f64) -> f64 {
+                [$(self.$index.error_norm(other.$index, abs_tolerance.$index, rel_tolerance)),*]
+                    .into_iter()
+                    .fold(0.0, f64::max)
+            }
+        }
+    };
+}
+
+tuple_state!(A: 0, B: 1);
+tuple_state!(A: 0, B: 1, C: 2);
+tuple_state!(A: 0, B: 1, C: 2, D: 3);
+
+impl<T, S, const N: usize> OdeState<T> for [S; N]
+where
+    T: ValueType,
+    S: OdeState<T>,
+{
+    type Derivative = [S::Derivative; N];
+
+    fn advance(self, derivative: Self::Derivative, dt: Time<T>) -> Self {
+        std::array::from_fn(|i| self[i].advance(derivative[i], dt))
+    }
+
+    fn error_norm(self, other: Self, abs_tolerance: Self, rel_tolerance: f64) -> f64 {
+        (0..N)
+            .map(|i| self[i].error_norm(other[i], abs_tolerance[i], rel_tolerance))
+            .fold(0.0, f64::max)
+    }
+}
+
+/// Advance `y` from `t` by `dt` using a single explicit euler step.
+pub fn euler_step<T, S, F>(f: &F, t: Time<T>, y: S, dt: Time<T>) -> S
+where
+    T: ValueType,
+    S: OdeState<T>,
+    F: Fn(Time<T>, S) -> S::Derivative,
+{
+    y.advance(f(t, y), dt)
+}
+
+/// Advance `y` from `t` by `dt` using a single classic fourth order Runge-Kutta step.
+pub fn rk4_step<T, S, F>(f: &F, t: Time<T>, y: S, dt: Time<T>) -> S
+where
+    T: ValueType,
+    S: OdeState<T>,
+    F: Fn(Time<T>, S) -> S::Derivative,
+{
+    let half = dt / T::new_from_real_f64(2.0);
+    let k1 = f(t, y);
+    let k2 = f(time_add(t, half), y.advance(k1, half));
+    let k3 = f(time_add(t, half), y.advance(k2, half));
+    let k4 = f(time_add(t, dt), y.advance(k3, dt));
+
+    let sixth = dt / T::new_from_real_f64(6.0);
+    let third = dt / T::new_from_real_f64(3.0);
+    y.advance(k1, sixth)
+        .advance(k2, third)
+        .advance(k3, third)
+        .advance(k4, sixth)
+}
+
+/// Solve the initial value problem `y' = f(t, y)`, `y(t0) = y0` using `steps` explicit euler steps of size `dt`.
+///
+/// Returns the initial state followed by the state after each step, together with its time.
+pub fn euler<T, S, F>(f: F, t0: Time<T>, y0: S, dt: Time<T>, steps: usize) -> Vec<(Time<T>, S)>
+where
+    T: ValueType,
+    S: OdeState<T>,
+    F: Fn(Time<T>, S) -> S::Derivative,
+{
+    fixed_step(euler_step, f, t0, y0, dt, steps)
+}
+
+/// Solve the initial value problem `y' = f(t, y)`, `y(t0) = y0` using `steps` Runge-Kutta steps of size `dt`.
+///
+/// Returns the initial state followed by the state after each step, together with its time.
+pub fn rk4<T, S, F>(f: F, t0: Time<T>, y0: S, dt: Time<T>, steps: usize) -> Vec<(Time<T>, S)>
+where
+    T: ValueType,
+    S: OdeState<T>,
+    F: Fn(Time<T>, S) -> S::Derivative,
+{
+    fixed_step(rk4_step, f, t0, y0, dt, steps)
+}
+
+fn fixed_step<T, S, F>(
+    step: fn(&F, Time<T>, S, Time<T>) -> S,
+    f: F,
+    t0: Time<T>,
+    y0: S,
+    dt: Time<T>,
+    steps: usize,
+) -> Vec<(Time<T>, S)>
+where
+    T: ValueType,
+    S: OdeState<T>,
+    F: Fn(Time<T>, S) -> S::Derivative,
+{
+    let mut result = Vec::with_capacity(steps + 1);
+    result.push((t0, y0));
+    let (mut t, mut y) = (t0, y0);
+    for i in 1..=steps {
+        y = step(&f, t, y, dt);
+        t = time_add(t0, dt * T::new_from_real_f64(i as f64));
+        result.push((t, y));
+    }
+    result
+}
+
+/// Solve the initial value problem `y' = f(t, y)`, `y(t0) = y0` up to `t_end`
+/// using the adaptive Dormand-Prince 5(4) method.
+///
+/// The step size starts at `initial_step` and is adapted such that the local error
+/// of every component stays below `abs_tolerance + rel_tolerance * |y|`.
+/// Note that `abs_tolerance` is a state itself, so every component gets its own, typed, tolerance.
+///
+/// Integrates backwards in time if `t_end` is before `t0`; the sign of `initial_step` does not matter.
+///
+/// Returns all accepted steps including the initial state,
+/// or `None` if the step size becomes too small to make progress or the derivative is not finite.
+pub fn rk45<T, S, F>(
+    f: F,
+    t0: Time<T>,
+    y0: S,
+    t_end: Time<T>,
+    initial_step: Time<T>,
+    abs_tolerance: S,
+    rel_tolerance: f64,
+) -> Option<Vec<(Time<T>, S)>>
+where
+    T: ValueType + RealValueType + Float,
+    S: OdeState<T>,
+    F: Fn(Time<T>, S) -> S::Derivative,
+{
+    const C: [f64; 7] = [0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+    const A: [&[f64]; 7] = [
+        &[],
+        &[1.0 / 5.0],
+        &[3.0 / 40.0, 9.0 / 40.0],
+        &[44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0],
+        &[
+            19372.0 / 6561.0,
+            -25360.0 / 2187.0,
+            64448.0 / 6561.0,
+            -212.0 / 729.0,
+        ],
+        &[
+            9017.0 / 3168.0,
+            -355.0 / 33.0,
+            46732.0 / 5247.0,
+            49.0 / 176.0,
+            -5103.0 / 18656.0,
+        ],
+        &[
+            35.0 / 384.0,
+            0.0,
+            500.0 / 1113.0,
+            125.0 / 192.0,
+            -2187.0 / 6784.0,
+            11.0 / 84.0,
+        ],
+    ];
+    const B4: [f64; 7] = [
+        5179.0 / 57600.0,
+        0.0,
+        7571.0 / 16695.0,
+        393.0 / 640.0,
+        -92097.0 / 339200.0,
+        187.0 / 2100.0,
+        1.0 / 40.0,
+    ];
+
+    let combine = |y: S, ks: &[S::Derivative], weights: &[f64], dt: Time<T>| {
+        ks.iter()
+            .zip(weights.iter())
+            .filter(|(_, &w)| w != 0.0)
+            .fold(y, |acc, (&k, &w)| {
+                acc.advance(k, dt * T::new_from_real_f64(w))
+            })
+    };
+
+    let end = t_end.base_value();
+    let mut t = t0.base_value();
+    let mut y = y0;
+    // the step size is kept positive, `direction` is the sign of the actual step
+    let direction = if end < t { -T::one() } else { T::one() };
+    let mut h = initial_step.base_value().abs();
+    let mut result = vec![(t0, y0)];
+
+    for _ in 0..MAX_ADAPTIVE_STEPS {
+        let remaining = (end - t) * direction;
+        if remaining <= T::zero() {
+            return Some(result);
+        }
+        h = h.min(remaining);
+        let dt = h * direction;
+        if t + dt == t {
+            return None;
+        }
+
+        let step = Time::new_base(dt);
+        let mut ks = Vec::with_capacity(7);
+        for (c, a) in C.iter().zip(A.iter()) {
+            let stage = combine(y, &ks, a, step);
+            ks.push(f(Time::new_base(t + dt * T::new_from_real_f64(*c)), stage));
+        }
+        let y5 = combine(y, &ks, A[6], step);
+        let y4 = combine(y, &ks, &B4, step);
+
+        let error = y5.error_norm(y4, abs_tolerance, rel_tolerance);
+        if !error.is_finite() {
+            // shrinking the step won't get rid of a NaN or infinite derivative
+            return None;
+        }
+        if error <= 1.0 {
+            t = t + dt;
+            y = y5;
+            result.push((Time::new_base(t), y));
+        }
+        let factor = if error == 0.0 {
+            5.0
+        } else {
+            (0.9 * error.powf(-0.2)).clamp(0.2, 5.0)
+        };
+        h = h * T::new_from_real_f64(factor);
+    }
+    None
+}
+
+fn time_add<T>(t: Time<T>, dt: Time<T>) -> Time<T>
+where
+    T: ValueType,
+{
+    Time::new_base(t.base_value() + dt.base_value())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::si::*;
+
+    #[test]
+    fn falling_ball() {
+        let g = Acceleration64::new::<MetresPerSecondSquared>(-9.81);
+        let f = |_t: Time64, (_x, v): (Length64, Velocity64)| (v, g);
+        let y0 = (
+            Length::new::<Metre>(100.0),
+            Velocity64::new::<MetresPerSecond>(0.0),
+        );
+        let dt = Time::new::<Second>(0.1);
+
+        let states = rk4(f, Time::new::<Second>(0.0), y0, dt, 20);
+        assert_eq!(states.len(), 21);
+        let (t, (x, v)) = states[20];
+        assert!((t.get::<Second>() - 2.0).abs() < 1e-12);
+        assert!((x.get::<Metre>() - (100.0 - 0.5 * 9.81 * 4.0)).abs() < 1e-9);
+        assert!((v.get::<MetresPerSecond>() + 9.81 * 2.0).abs() < 1e-9);
+
+        let (_, (_, v_euler)) = euler(f, Time::new::<Second>(0.0), y0, dt, 20)[20];
+        assert!((v_euler.get::<MetresPerSecond>() + 9.81 * 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn adaptive_decay() {
+        // Newtonian cooling of a temperature difference, dT/dt = -T / tau
+        let tau = Time64::new::<Second>(2.0);
+        let f = |_t: Time64, temp: TemperatureInterval64| -temp / tau;
+        let t_end = Time::new::<Second>(4.0);
+
+        let states = rk45(
+            f,
+            Time::new::<Second>(0.0),
+            TemperatureInterval::new::<Kelvin>(10.0),
+            t_end,
+            Time::new::<Second>(0.1),
+            TemperatureInterval::new::<Kelvin>(1e-9),
+            1e-9,
+        )
+        .expect("converges");
+        let (t, temp) = *states.last().unwrap();
+        assert_eq!(t, t_end);
+        assert!((temp.get::<Kelvin>() - 10.0 * (-2.0f64).exp()).abs() < 1e-7);
+    }
+
+    #[test]
+    fn adaptive_backwards() {
+        // integrating the cooling backwards recovers the initial difference
+        let tau = Time64::new::<Second>(2.0);
+        let f = |_t: Time64, temp: TemperatureInterval64| -temp / tau;
+        let t0 = Time::new::<Second>(1.0);
+        let t_end = Time::new::<Second>(0.0);
+
+        let states = rk45(
+            f,
+            t0,
+            TemperatureInterval::new::<Kelvin>(10.0 * (-0.5f64).exp()),
+            t_end,
+            Time::new::<Second>(0.1),
+            TemperatureInterval::new::<Kelvin>(1e-9),
+            1e-9,
+        )
+        .expect("converges");
+        assert!(states.len() > 2);
+        assert!(states.windows(2).all(|w| w[1].0 < w[0].0));
+        let (t, temp) = *states.last().unwrap();
+        assert_eq!(t, t_end);
+        assert!((temp.get::<Kelvin>() - 10.0).abs() < 1e-7);
+    }
+
+    #[test]
+    fn adaptive_nan() {
+        let f = |t: Time64, temp: TemperatureInterval64| {
+            if t > Time64::new::<Second>(1.0) {
+                temp / Time64::new::<Second>(f64::NAN)
+            } else {
+                -temp / Time64::new::<Second>(2.0)
+            }
+        };
+        let states = rk45(
+            f,
+            Time::new::<Second>(0.0),
+            TemperatureInterval::new::<Kelvin>(10.0),
+            Time::new::<Second>(4.0),
+            Time::new::<Second>(0.1),
+            TemperatureInterval::new::<Kelvin>(1e-9),
+            1e-9,
+        );
+        assert!(states.is_none());
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{
+    constants::{
+        constant,
+        MAX_LUMINOUS_EFFICACY,
+    },
+    quantity::WithValue,
+    si::*,
+    value_type::{
+        RealValueType,
+        ValueType,
+    },
+};
+
+/// Luminous flux of monochromatic light at 555 nm with the given radiant flux
+pub fn luminous_flux_at_555nm<T>(
+    radiant_flux: WithValue<T, RadiantFlux64>,
+) -> WithValue<T, LuminousFlux64>
+where
+    T: ValueType + RealValueType,
+{
+    radiant_flux * constant(MAX_LUMINOUS_EFFICACY)
+}
+
+/// Radiant flux of monochromatic light at 555 nm with the given luminous flux
+pub fn radiant_flux_at_555nm<T>(
+    luminous_flux: WithValue<T, LuminousFlux64>,
+) -> WithValue<T, RadiantFlux64>
+where
+    T: ValueType + RealValueType,
+{
+    luminous_flux / constant(MAX_LUMINOUS_EFFICACY)
+}
+
+/// Illuminance of monochromatic light at 555 nm with the given irradiance
+pub fn illuminance_at_555nm<T>(
+    irradiance: WithValue<T, Irradiance64>,
+) -> WithValue<T, Illuminance64>
+where
+    T: ValueType + RealValueType,
+{
+    irradiance * constant(MAX_LUMINOUS_EFFICACY)
+}
+
+/// Irradiance of monochromatic light at 555 nm with the given illuminance
+pub fn irradiance_at_555nm<T>(
+    illuminance: WithValue<T, Illuminance64>,
+) -> WithValue<T, Irradiance64>
+where
+    T: ValueType + RealValueType,
+{
+    illuminance / constant(MAX_LUMINOUS_EFFICACY)
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::quantity::QuantityType;
+
+    #[test]
+    fn lamp() {
+        // an isotropic 100 cd source emits 4π·100 lm
+        let intensity = LouminousIntensity64::new::<Candela>(100.0);
+        let full_sphere = SolidAngle64::new::<Steradian>(4.0 * std::f64::consts::PI);
+        let flux: LuminousFlux64 = intensity * full_sphere;
+        assert!((flux.get::<Lumen>() - 1256.6370614).abs() < 1e-6);
+
+        let area = Area64::new::<SquareMetre>(2.0);
+        let illuminance: Illuminance64 = flux / area;
+        assert!((illuminance.get::<Lux>() - 628.3185307).abs() < 1e-6);
+
+        let efficacy: LuminousEfficacy64 = flux / Power64::new::<Watt>(10.0);
+        assert!((efficacy.get::<LumenPerWatt>() - 125.66370614).abs() < 1e-6);
+
+        let back: LouminousIntensity64 = flux / full_sphere;
+        assert!((back.get::<Candela>() - 100.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn radiometric_conversion() {
+        let flux = luminous_flux_at_555nm(RadiantFlux64::new::<Watt>(1.0));
+        assert_eq!(flux.get::<Lumen>(), 683.0);
+        assert_eq!(radiant_flux_at_555nm(flux).get::<Watt>(), 1.0);
+
+        let illuminance = illuminance_at_555nm(Irradiance64::new::<WattPerSquareMetre>(2.0));
+        assert_eq!(illuminance.get::<KiloLux>(), 1.366);
+        assert_eq!(
+            irradiance_at_555nm(illuminance).get::<WattPerSquareMetre>(),
+            2.0
+        );
+        let dim = luminous_flux_at_555nm(RadiantFlux::<f32>::new::<MilliWatt>(2.0));
+        assert_eq!(dim.get::<Lumen>(), 1.366);
+
+        let radiance: Radiance64 =
+            RadiantIntensity64::new::<WattPerSteradian>(3.0) / Area64::new::<SquareMetre>(1.5);
+        assert_eq!(radiance.get::<WattPerSteradianPerSquareMetre>(), 2.0);
+
+        // multiplying by steradians cancels the per steradian
+        let radiant_intensity = RadiantIntensity64::new::<WattPerSteradian>(2.0);
+        let full_sphere = SolidAngle64::new::<Steradian>(4.0 * std::f64::consts::PI);
+        let power: RadiantFlux64 = radiant_intensity * full_sphere;
+        assert!((power.get::<Watt>() - 8.0 * std::f64::consts::PI).abs() < 1e-12);
+        let solid_angle: SolidAngle64 = power / radiant_intensity;
+        assert_eq!(solid_angle, full_sphere);
+        let intensity: LouminousIntensity64 = radiant_intensity * MAX_LUMINOUS_EFFICACY;
+        assert_eq!(intensity.get::<Candela>(), 1366.0);
+    }
+}
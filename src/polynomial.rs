@@ -0,0 +1,208 @@
+use std::{
+    marker::PhantomData,
+    ops::{
+        Div,
+        Mul,
+    },
+};
+
+use num::traits::ConstZero;
+use typenum::{
+    IsGreaterOrEqual,
+    True,
+    Z0,
+};
+
+use crate::{
+    dimension::{
+        DimInverse,
+        DimPow,
+        Dimension,
+    },
+    integrate::Integral,
+    kind::{
+        KindDiv,
+        KindMul,
+    },
+    quantity::{
+        Derivative,
+        Quantity,
+        QuantityType,
+    },
+    value_type::ValueType,
+};
+
+/// The dimension of the coefficient of degree `P` in a polynomial of `Y` over `X`: `Y / X^P`
+pub type CoefficientDim<X, Y, P> = <<Y as QuantityType>::Dimension as Mul<
+    <<<X as QuantityType>::Dimension as DimPow<P>>::Output as DimInverse>::Output,
+>>::Output;
+
+/// The coefficient of degree `P` in a polynomial of `Y` over `X`
+pub type Coefficient<X, Y, P> =
+    Quantity<<X as QuantityType>::DataType, CoefficientDim<X, Y, P>, ()>;
+
+/// A polynomial mapping `X` to `Y`.
+///
+/// The coefficient of degree `n` has the type `Y / X^n`, which is checked at compile time:
+/// ```
+/// # use silib::{polynomial::Polynomial, quantity::QuantityType, si::*};
+/// // s = 2 m + 3 m/s * t + 0.5 m/s² * t²
+/// let s = Polynomial::<Time64, Length64>::constant(Length64::new::<Metre>(2.0))
+///     .with_coefficient::<typenum::P1>(Velocity64::new::<MetresPerSecond>(3.0))
+///     .with_coefficient::<typenum::P2>(Acceleration64::new::<MetresPerSecondSquared>(0.5));
+/// let v: Polynomial<Time64, Velocity64> = s.derivative();
+/// assert_eq!(v.eval(Time::new::<Second>(2.0)).get::<MetresPerSecond>(), 5.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Polynomial<X, Y>
+where
+    X: QuantityType,
+{
+    coefficients: Vec<X::DataType>,
+    _types: PhantomData<(X, Y)>,
+}
+
+impl<X, Y> Polynomial<X, Y>
+where
+    X: QuantityType,
+    Y: QuantityType<DataType = X::DataType>,
+{
+    /// Create a polynomial from its base coefficients, lowest degree first
+    pub(crate) fn from_base(coefficients: Vec<X::DataType>) -> Self {
+        Self {
+            coefficients,
+            _types: PhantomData,
+        }
+    }
+
+    /// The polynomial that is `c0` everywhere
+    pub fn constant(c0: Y) -> Self {
+        Self::from_base(vec![c0.base_value()])
+    }
+
+    /// Set the coefficient of degree `P`, where `P` is a non-negative typenum integer, e.g. `typenum::P2`.
+    pub fn with_coefficient<P>(mut self, coefficient: Coefficient<X, Y, P>) -> Self
+    where
+        P: typenum::Integer + IsGreaterOrEqual<Z0, Output = True>,
+        X::Dimension: DimPow<P>,
+        <X::Dimension as DimPow<P>>::Output: DimInverse,
+        Y::Dimension: Mul<<<X::Dimension as DimPow<P>>::Output as DimInverse>::Output>,
+        CoefficientDim<X, Y, P>: Dimension,
+    {
+        let degree = P::to_i32() as usize;
+        if self.coefficients.len() <= degree {
+            self.coefficients.resize(degree + 1, X::DataType::ZERO);
+        }
+        self.coefficients[degree] = coefficient.base_value();
+        self
+    }
+
+    /// The coefficient of degree `P`, zero if `P` is larger than the degree.
+    pub fn coefficient<P>(&self) -> Coefficient<X, Y, P>
+    where
+        P: typenum::Integer + IsGreaterOrEqual<Z0, Output = True>,
+        X::Dimension: DimPow<P>,
+        <X::Dimension as DimPow<P>>::Output: DimInverse,
+        Y::Dimension: Mul<<<X::Dimension as DimPow<P>>::Output as DimInverse>::Output>,
+        CoefficientDim<X, Y, P>: Dimension,
+    {
+        let degree = P::to_i32() as usize;
+        Quantity::new_base(
+            self.coefficients
+                .get(degree)
+                .copied()
+                .unwrap_or(X::DataType::ZERO),
+        )
+    }
+
+    /// Degree of the polynomial
+    pub fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+
+    /// Evaluate the polynomial at `x`
+    pub fn eval(&self, x: X) -> Y {
+        let x = x.base_value();
+        Y::from_base(
+            self.coefficients
+                .iter()
+                .rev()
+                .fold(X::DataType::ZERO, |acc, c| acc * x + *c),
+        )
+    }
+
+    /// The derivative, a polynomial of `Y / X`
+    pub fn derivative(&self) -> Polynomial<X, Derivative<X, Y>>
+    where
+        Y::Dimension: Div<X::Dimension>,
+        <Y::Dimension as Div<X::Dimension>>::Output: Dimension,
+        Y::Kind: KindDiv<X::Kind>,
+    {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(n, c)| *c * X::DataType::new_from_real_f64(n as f64))
+            .collect::<Vec<_>>();
+        Polynomial::from_base(if coefficients.is_empty() {
+            vec![X::DataType::ZERO]
+        } else {
+            coefficients
+        })
+    }
+
+    /// The antiderivative, a polynomial of `Y * X` that is `constant` at `x = 0`
+    pub fn integral(&self, constant: Integral<X, Y>) -> Polynomial<X, Integral<X, Y>>
+    where
+        Y::Dimension: Mul<X::Dimension>,
+        <Y::Dimension as Mul<X::Dimension>>::Output: Dimension,
+        Y::Kind: KindMul<X::Kind>,
+    {
+        Polynomial::from_base(
+            std::iter::once(constant.base_value())
+                .chain(
+                    self.coefficients
+                        .iter()
+                        .enumerate()
+                        .map(|(n, c)| *c / X::DataType::new_from_real_f64((n + 1) as f64)),
+                )
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::si::*;
+
+    #[test]
+    fn strain_gauge_curve() {
+        // resistance of a gauge over temperature, r = 120 Ω + 0.1 Ω/K * t + 0.002 Ω/K² * t²
+        let r = Polynomial::<TemperatureInterval64, ElectricResistance64>::constant(
+            ElectricResistance64::new::<Ohm>(120.0),
+        )
+        .with_coefficient::<typenum::P2>(Quantity::new_base(0.002))
+        .with_coefficient::<typenum::P1>(Quantity::new_base(0.1));
+        assert_eq!(r.degree(), 2);
+        assert_eq!(r.coefficient::<typenum::P2>().base_value(), 0.002);
+        assert_eq!(r.coefficient::<typenum::P3>().base_value(), 0.0);
+
+        let t = TemperatureInterval::new::<Kelvin>(10.0);
+        assert!((r.eval(t).get::<Ohm>() - 121.2).abs() < 1e-12);
+        assert!((r.derivative().eval(t).base_value() - 0.14).abs() < 1e-12);
+    }
+
+    #[test]
+    fn integral() {
+        let v = Polynomial::<Time64, Velocity64>::constant(Velocity64::new::<MetresPerSecond>(3.0))
+            .with_coefficient::<typenum::P1>(Acceleration64::new::<MetresPerSecondSquared>(1.0));
+        let s: Polynomial<Time64, Length64> = v.integral(Length::new::<Metre>(2.0));
+        assert_eq!(s.eval(Time::new::<Second>(2.0)).get::<Metre>(), 10.0);
+        assert_eq!(
+            s.derivative().eval(Time::new::<Second>(2.0)),
+            v.eval(Time::new::<Second>(2.0))
+        );
+    }
+}
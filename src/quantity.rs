@@ -79,6 +79,18 @@ pub trait QuantityType {
     ) -> UnitFormatter<Self::DataType, U>;
 }
 
+/// The type of the derivative of `Y` with respect to `X`. Length over time is velocity, and so on.
+pub type Derivative<X, Y> = Quantity<
+    <X as QuantityType>::DataType,
+    <<Y as QuantityType>::Dimension as Div<<X as QuantityType>::Dimension>>::Output,
+    <<Y as QuantityType>::Kind as KindDiv<<X as QuantityType>::Kind>>::Output,
+>;
+
+/// The quantity `Q` with its value stored as `T`, e.g. `WithValue<f32, Length64>` is a `Length<f32>`.
+///
+/// Unlike the derived aliases, this lets `T` be inferred when it appears in function arguments.
+pub type WithValue<T, Q> = Quantity<T, <Q as QuantityType>::Dimension, <Q as QuantityType>::Kind>;
+
 /// Represent a specific quantity stored
 ///
 /// `DataType` is the actual stored value
@@ -94,7 +106,7 @@ pub trait QuantityType {
 ///
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug)]
 pub struct Quantity<DataType, Dim, K>
 where
     DataType: ValueType,
@@ -107,6 +119,24 @@ where
     value: DataType,
 }
 
+// Clone and Copy are implemented by hand, as deriving them would require Dim and K to be Copy as well.
+impl<DataType, Dim, K> Clone for Quantity<DataType, Dim, K>
+where
+    DataType: ValueType,
+    Dim: Dimension,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<DataType, Dim, K> Copy for Quantity<DataType, Dim, K>
+where
+    DataType: ValueType,
+    Dim: Dimension,
+{
+}
+
 impl<DataType, Dim, K> Quantity<DataType, Dim, K>
 where
     DataType: ValueType,
@@ -157,7 +187,7 @@ where
     where
         UnitType: Unit + QuantityConversion<DataType, Dim, K>,
     {
-        UnitFormatter::new(self.get::<UnitType>())
+        UnitFormatter::with_print_name(self.get::<UnitType>(), UnitType::symbol::<Dim>())
     }
 }
 
@@ -345,7 +375,7 @@ where
     fn formatted<U: Unit + QuantityConversion<Self::DataType, Self::Dimension, Self::Kind>>(
         &self,
     ) -> UnitFormatter<Self::DataType, U> {
-        UnitFormatter::new(self.get::<U>())
+        UnitFormatter::with_print_name(self.get::<U>(), U::symbol::<Self::Dimension>())
     }
 }
 
@@ -622,6 +652,7 @@ where
 {
     no_param_methods!(
         arg -> Quantity<<Complex<T> as ComplexFloat>::Real,NoDim,AngleKind>;
+        conj -> Quantity<Complex<T>,Dim,K>;
         im -> Quantity<<Complex<T> as ComplexFloat>::Real,Dim,K>;
         re -> Quantity<<Complex<T> as ComplexFloat>::Real,Dim,K>;
     );
@@ -665,6 +696,18 @@ where
     no_param_methods!(
         norm -> Quantity<T,Dim,K>;
     );
+
+    /// Create a complex quantity from its magnitude and phase angle
+    pub fn from_polar(
+        magnitude: Quantity<T, Dim, K>,
+        phase: Quantity<T, NoDim, AngleKind>,
+    ) -> Self {
+        Self {
+            _dim: Default::default(),
+            _kind: Default::default(),
+            value: Complex::from_polar(magnitude.value, phase.value),
+        }
+    }
 }
 
 impl<T, Dim, K> Quantity<Complex<T>, Dim, K>
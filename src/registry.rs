@@ -0,0 +1,514 @@
+//! A runtime registry of units, e.g. to let users choose display units in a UI.
+//!
+//! Units are types, so they cannot be enumerated at runtime by themselves.
+//! The registry stores a [`UnitInfo`] for every unit/quantity pair registered with it,
+//! and [`UnitRegistry::si`] knows all units declared in [`crate::si`] and [`crate::us_customary`].
+//! The quantity macros register their units themselves, so every unit they declare is included:
+//! ```
+//! # use silib::{quantity::QuantityType, registry::UnitRegistry, si::*};
+//! let registry = UnitRegistry::si();
+//! let kilopascal = registry.find::<Pressure64>("kPa").unwrap();
+//! assert_eq!(kilopascal.long_name(), "KiloPascal");
+//!
+//! let pressure = Pressure64::new::<Pascal>(101325.0);
+//! assert!((kilopascal.from_base(pressure.base_value()) - 101.325).abs() < 1e-9);
+//! ```
+
+use std::{
+    any::{
+        type_name,
+        TypeId,
+    },
+    collections::HashMap,
+    fmt::{
+        Debug,
+        Formatter,
+    },
+    marker::PhantomData,
+    sync::OnceLock,
+};
+
+use crate::{
+    dimension::Dimension,
+    quantity::{
+        Quantity,
+        QuantityType,
+    },
+    unit::{
+        LinearConversion,
+        QuantityConversion,
+        Unit,
+    },
+    value_type::{
+        RealValueType,
+        ValueType,
+    },
+};
+
+/// Everything known about a unit of some quantity at runtime
+#[derive(Debug, Clone, Copy)]
+pub struct UnitInfo {
+    quantity: &'static str,
+    long_name: &'static str,
+    print_name: &'static str,
+    dimension: [i32; 7],
+    kind: TypeId,
+    kind_name: &'static str,
+    linear: Option<(f64, f64)>,
+    to_base: fn(f64) -> f64,
+    from_base: fn(f64) -> f64,
+}
+
+impl UnitInfo {
+    /// Create the info for unit `U` of the quantity `Q`, which is called `quantity`
+    pub fn new<Q, U>(quantity: &'static str) -> Self
+    where
+        Q: QuantityType<DataType = f64>,
+        Q::Kind: 'static,
+        U: Unit + QuantityConversion<f64, Q::Dimension, Q::Kind>,
+    {
+        Self {
+            quantity,
+            long_name: U::long_name(),
+            print_name: U::print_name(),
+            dimension: Q::Dimension::EXPONENTS,
+            kind: TypeId::of::<Q::Kind>(),
+            kind_name: type_name::<Q::Kind>(),
+            linear: U::linear(),
+            to_base: U::convert_to_base,
+            from_base: U::convert_from_base,
+        }
+    }
+
+    /// The name of the quantity this unit was registered for, e.g. "Pressure"
+    pub fn quantity(&self) -> &'static str {
+        self.quantity
+    }
+
+    /// The long name of the unit, e.g. "KiloPascal"
+    pub fn long_name(&self) -> &'static str {
+        self.long_name
+    }
+
+    /// The symbol of the unit, e.g. "kPa"
+    pub fn print_name(&self) -> &'static str {
+        self.print_name
+    }
+
+    /// The exponents of the SI base dimensions, see [`Dimension::EXPONENTS`]
+    pub fn dimension(&self) -> [i32; 7] {
+        self.dimension
+    }
+
+    /// The type name of the kind of the quantity
+    pub fn kind_name(&self) -> &'static str {
+        self.kind_name
+    }
+
+    /// The factor to the base unit. `None` for non-linear units.
+    pub fn factor(&self) -> Option<f64> {
+        self.linear.map(|(factor, _)| factor)
+    }
+
+    /// The offset to the base unit, in this unit. `None` for non-linear units.
+    pub fn offset(&self) -> Option<f64> {
+        self.linear.map(|(_, offset)| offset)
+    }
+
+    /// Whether this unit converts via factor and offset alone
+    pub fn is_linear(&self) -> bool {
+        self.linear.is_some()
+    }
+
+    /// Convert a value in this unit to the base unit
+    pub fn to_base(&self, value: f64) -> f64 {
+        (self.to_base)(value)
+    }
+
+    /// Convert a value in the base unit to this unit
+    pub fn from_base(&self, base_value: f64) -> f64 {
+        (self.from_base)(base_value)
+    }
+
+    /// Whether this is a unit of the quantity type `Q`, that is same dimension and kind
+    pub fn is_unit_of<Q>(&self) -> bool
+    where
+        Q: QuantityType,
+        Q::Kind: 'static,
+    {
+        self.dimension == Q::Dimension::EXPONENTS && self.kind == TypeId::of::<Q::Kind>()
+    }
+
+    /// Whether values can be converted between this and `other`
+    pub fn is_compatible(&self, other: &UnitInfo) -> bool {
+        self.dimension == other.dimension && self.kind == other.kind
+    }
+
+    /// Convert a value in this unit to the `other` unit. `None` if they are not compatible.
+    pub fn convert_to(&self, value: f64, other: &UnitInfo) -> Option<f64> {
+        self.is_compatible(other)
+            .then(|| other.from_base(self.to_base(value)))
+    }
+}
+
+/// A collection of units that can be queried at runtime
+#[derive(Debug, Clone, Default)]
+pub struct UnitRegistry {
+    units: Vec<UnitInfo>,
+    /// Positions in `units` by dimension and kind, the units of each quantity type
+    by_type: HashMap<([i32; 7], TypeId), Vec<usize>>,
+}
+
+impl UnitRegistry {
+    /// An empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A registry with all units of [`crate::si`] and [`crate::us_customary`].
+    ///
+    /// Units that other crates declare with the same macros are included as well.
+    pub fn si() -> Self {
+        let mut registrations: Vec<&Registration> = inventory::iter::<Registration>().collect();
+        registrations.sort_by_key(|registration| {
+            (
+                !registration.base,
+                registration.file,
+                registration.line,
+                registration.order,
+            )
+        });
+        let mut registry = Self::new();
+        for registration in registrations {
+            (registration.register)(&mut registry);
+        }
+        registry
+    }
+
+    /// The registry of [`UnitRegistry::si`], built once and shared by unit systems and [`DynUnit`]
+    pub(crate) fn shared_si() -> &'static Self {
+        static REGISTRY: OnceLock<UnitRegistry> = OnceLock::new();
+        REGISTRY.get_or_init(Self::si)
+    }
+
+    /// Add unit `U` of the quantity `Q`, which is called `quantity`
+    pub fn register<Q, U>(&mut self, quantity: &'static str)
+    where
+        Q: QuantityType<DataType = f64>,
+        Q::Kind: 'static,
+        U: Unit + QuantityConversion<f64, Q::Dimension, Q::Kind>,
+    {
+        let unit = UnitInfo::new::<Q, U>(quantity);
+        self.by_type
+            .entry((unit.dimension, unit.kind))
+            .or_default()
+            .push(self.units.len());
+        self.units.push(unit);
+    }
+
+    /// All registered units
+    pub fn units(&self) -> impl Iterator<Item = &UnitInfo> {
+        self.units.iter()
+    }
+
+    /// All units valid for the quantity type `Q`
+    pub fn units_for<Q>(&self) -> impl Iterator<Item = &UnitInfo>
+    where
+        Q: QuantityType,
+        Q::Kind: 'static,
+    {
+        self.units_of_type(Q::Dimension::EXPONENTS, TypeId::of::<Q::Kind>())
+    }
+
+    /// All units values in `unit` can be converted to, including itself if it is registered
+    pub fn compatible_units(&self, unit: &UnitInfo) -> impl Iterator<Item = &UnitInfo> {
+        self.units_of_type(unit.dimension, unit.kind)
+    }
+
+    fn units_of_type(&self, dimension: [i32; 7], kind: TypeId) -> impl Iterator<Item = &UnitInfo> {
+        self.by_type
+            .get(&(dimension, kind))
+            .into_iter()
+            .flatten()
+            .map(|&index| &self.units[index])
+    }
+
+    /// All units registered under the quantity name, e.g. "Pressure"
+    pub fn units_of_quantity<'a>(
+        &'a self,
+        quantity: &'a str,
+    ) -> impl Iterator<Item = &'a UnitInfo> + 'a {
+        self.units
+            .iter()
+            .filter(move |unit| unit.quantity == quantity)
+    }
+
+    /// All units with the given symbol. The same symbol may be registered for several quantities.
+    pub fn by_symbol<'a>(&'a self, symbol: &'a str) -> impl Iterator<Item = &'a UnitInfo> + 'a {
+        self.units
+            .iter()
+            .filter(move |unit| unit.print_name == symbol)
+    }
+
+    /// All units with the given long name
+    pub fn by_name<'a>(&'a self, long_name: &'a str) -> impl Iterator<Item = &'a UnitInfo> + 'a {
+        self.units
+            .iter()
+            .filter(move |unit| unit.long_name == long_name)
+    }
+
+    /// The name the quantity type `Q` is registered under, that of its first unit, e.g. "Pressure"
+    pub fn quantity_name<Q>(&self) -> Option<&'static str>
+    where
+        Q: QuantityType,
+        Q::Kind: 'static,
+    {
+        self.units_for::<Q>().next().map(UnitInfo::quantity)
+    }
+
+    /// The unit of the quantity type `Q` with the given symbol
+    pub fn find<Q>(&self, symbol: &str) -> Option<&UnitInfo>
+    where
+        Q: QuantityType,
+        Q::Kind: 'static,
+    {
+        self.units_for::<Q>().find(|unit| unit.print_name == symbol)
+    }
+}
+
+/// A unit of the quantity type `Q`, chosen at runtime.
+///
+/// Whether the unit fits `Q` is checked once when the handle is created,
+/// so [`DynUnit::quantity`] and [`DynUnit::value_of`] cannot fail:
+/// ```
+/// # use silib::{registry::{DynUnit, UnitRegistry}, si::*};
+/// let registry = UnitRegistry::si();
+/// let unit = DynUnit::<Velocity64>::find(&registry, "km*h⁻¹").unwrap();
+/// let speed = unit.quantity(36.0);
+/// assert!((unit.value_of(&speed) - 36.0).abs() < 1e-12);
+/// assert!(DynUnit::<Velocity64>::find(&registry, "m").is_none());
+/// ```
+pub struct DynUnit<Q> {
+    info: UnitInfo,
+    _quantity: PhantomData<Q>,
+}
+
+impl<Q> DynUnit<Q>
+where
+    Q: QuantityType,
+    Q::Kind: 'static,
+{
+    /// The handle for the compile time unit `U`.
+    /// Its quantity name is the one `Q` is registered under in [`UnitRegistry::si`], if any.
+    pub fn new<U>() -> Self
+    where
+        U: Unit + QuantityConversion<f64, Q::Dimension, Q::Kind>,
+    {
+        let quantity = UnitRegistry::shared_si()
+            .quantity_name::<Q>()
+            .unwrap_or_default();
+        Self {
+            info: UnitInfo::new::<Quantity<f64, Q::Dimension, Q::Kind>, U>(quantity),
+            _quantity: PhantomData,
+        }
+    }
+
+    /// The handle for a unit from a registry, `None` if it is no unit of `Q`
+    pub fn from_info(info: &UnitInfo) -> Option<Self> {
+        info.is_unit_of::<Q>().then_some(Self {
+            info: *info,
+            _quantity: PhantomData,
+        })
+    }
+
+    /// The unit of `Q` with the given symbol in the registry
+    pub fn find(registry: &UnitRegistry, symbol: &str) -> Option<Self> {
+        registry.find::<Q>(symbol).and_then(Self::from_info)
+    }
+
+    /// The handle for the unit of `Q` with the given long name in the registry
+    pub fn find_by_name(registry: &UnitRegistry, long_name: &str) -> Option<Self> {
+        registry.by_name(long_name).find_map(Self::from_info)
+    }
+}
+
+impl<Q> DynUnit<Q>
+where
+    Q: QuantityType,
+    Q::DataType: RealValueType,
+{
+    /// The quantity with the given value in this unit
+    pub fn quantity(&self, value: Q::DataType) -> Q {
+        let base = self.info.to_base(value.to_f64_mag());
+        Q::from_base(Q::DataType::new_from_real_f64(base))
+    }
+
+    /// The value of `quantity` in this unit
+    pub fn value_of(&self, quantity: &Q) -> Q::DataType {
+        Q::DataType::new_from_real_f64(self.info.from_base(quantity.base_value().to_f64_mag()))
+    }
+}
+
+impl<Q> DynUnit<Q> {
+    /// The runtime information about this unit
+    pub fn info(&self) -> &UnitInfo {
+        &self.info
+    }
+}
+
+impl<Q> Clone for DynUnit<Q> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Q> Copy for DynUnit<Q> {}
+
+impl<Q> Debug for DynUnit<Q> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("DynUnit").field(&self.info).finish()
+    }
+}
+
+/// A unit of a quantity, submitted by the quantity macros and collected by [`UnitRegistry::si`].
+///
+/// Every macro that gives a quantity a unit submits one of these, so the registry cannot miss a unit.
+#[doc(hidden)]
+pub struct Registration {
+    file: &'static str,
+    line: u32,
+    order: u32,
+    base: bool,
+    register: fn(&mut UnitRegistry),
+}
+
+impl Registration {
+    /// A registration declared at `file:line`, as the `order`th quantity of the macro call there.
+    /// `base` marks the base unit of a quantity, which is registered first.
+    pub const fn new(
+        file: &'static str,
+        line: u32,
+        order: u32,
+        base: bool,
+        register: fn(&mut UnitRegistry),
+    ) -> Self {
+        Self {
+            file,
+            line,
+            order,
+            base,
+            register,
+        }
+    }
+}
+
+inventory::collect!(Registration);
+
+/// Registers the prefixed versions of a unit, implemented by [`make_units!`](crate::make_units)
+#[doc(hidden)]
+pub trait RegisterUnit: Unit {
+    /// The unit the prefixes apply to, `Kilogram` for grams and the unit itself otherwise
+    type Stem;
+
+    /// Add the prefixed versions of this unit for the quantity `Q`, which is called `quantity`
+    fn register_prefixed<Q>(_registry: &mut UnitRegistry, _quantity: &'static str)
+    where
+        Q: QuantityType<DataType = f64>,
+        Q::Kind: 'static,
+        Self::Stem: LinearConversion<f64, Q::Dimension, Q::Kind>,
+    {
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::si::*;
+
+    #[test]
+    fn registered_by_macros() {
+        let registry = UnitRegistry::si();
+        // base units come first, in the order they are declared
+        let first: Vec<_> = registry.units().take(2).map(UnitInfo::long_name).collect();
+        assert_eq!(first, ["Metre", "QuettaMetre"]);
+        assert!(registry.find::<Area64>("ac").is_some());
+        assert!(registry.find::<Mass64>("g").is_some());
+        assert!(registry.find::<AngularVelocity64>("kHz").is_some());
+        assert!(registry.find::<MolarConcentration64>("mM").is_some());
+        assert!(registry.find::<Irradiance64>("mag").is_some());
+        assert_eq!(registry.units_of_quantity("Time").count(), 30);
+    }
+
+    #[test]
+    fn lookup() {
+        let registry = UnitRegistry::si();
+        let pressures: Vec<_> = registry.units_for::<Pressure64>().collect();
+        assert!(pressures
+            .iter()
+            .any(|unit| unit.long_name() == "KiloPascal"));
+        assert!(pressures.iter().any(|unit| unit.long_name() == "Bar"));
+        assert!(pressures
+            .iter()
+            .all(|unit| unit.dimension() == [-1, 1, -2, 0, 0, 0, 0]));
+        // elastic moduli are measured in pascal too, but are another kind
+        assert_eq!(registry.by_symbol("GPa").count(), 2);
+        assert_eq!(registry.units_of_quantity("ElasticModulus").count(), 25);
+
+        let milligram = registry.find::<Mass64>("mg").unwrap();
+        assert_eq!(milligram.long_name(), "MilliGram");
+        assert!((milligram.to_base(1.0) - 1e-6).abs() < 1e-20);
+        assert_eq!(
+            registry.compatible_units(milligram).count(),
+            registry.units_for::<Mass64>().count()
+        );
+        assert!(registry.find::<Mass64>("kg").is_some());
+        assert!(registry.by_name("KiloGram").next().is_none());
+        assert!(registry.find::<Length64>("kg").is_none());
+    }
+
+    #[test]
+    fn conversions() {
+        let registry = UnitRegistry::si();
+        let fahrenheit = registry.find::<ThermodynamicTemperature64>("°F").unwrap();
+        let celsius = registry.find::<ThermodynamicTemperature64>("°C").unwrap();
+        assert_eq!(celsius.offset(), Some(273.15));
+        assert!((fahrenheit.convert_to(212.0, celsius).unwrap() - 100.0).abs() < 1e-12);
+        let kelvin = registry.find::<TemperatureInterval64>("K").unwrap();
+        assert!(celsius.convert_to(1.0, kelvin).is_none());
+
+        let mpg = registry.find::<FuelConsumption64>("mpg").unwrap();
+        assert!(!mpg.is_linear());
+        let per_100km = registry.by_name("LitrePerHundredKilometre").next().unwrap();
+        assert!((mpg.convert_to(30.0, per_100km).unwrap() - 7.840486).abs() < 1e-6);
+        assert_eq!(registry.units_for::<ReynoldsNumber64>().count(), 1);
+    }
+
+    #[test]
+    fn dyn_units() {
+        let registry = UnitRegistry::si();
+        let celsius = DynUnit::<ThermodynamicTemperature64>::find(&registry, "°C").unwrap();
+        let temperature = celsius.quantity(25.0);
+        assert!((temperature.get::<Kelvin>() - 298.15).abs() < 1e-12);
+        assert!((celsius.value_of(&temperature) - 25.0).abs() < 1e-12);
+
+        let kelvin = DynUnit::<ThermodynamicTemperature64>::new::<Kelvin>();
+        assert!((kelvin.value_of(&temperature) - 298.15).abs() < 1e-12);
+        assert_eq!(kelvin.info().print_name(), "K");
+        assert_eq!(kelvin.info().quantity(), "ThermodynamicTemperature");
+        assert_eq!(
+            DynUnit::<Pressure64>::new::<Bar>().info().quantity(),
+            "Pressure"
+        );
+
+        // the handle is checked against the quantity type, including its kind
+        let pascal = registry.by_name("Pascal").next().unwrap();
+        assert!(DynUnit::<Pressure64>::from_info(pascal).is_some());
+        assert!(DynUnit::<Energy64>::from_info(pascal).is_none());
+        assert!(DynUnit::<ElasticModulus64>::find_by_name(&registry, "GigaPascal").is_some());
+        assert!(DynUnit::<Strain64>::find(&registry, "Pa").is_none());
+
+        let mpg = DynUnit::<FuelConsumption32>::find(&registry, "mpg").unwrap();
+        let consumption = mpg.quantity(40.0);
+        assert!((consumption.get::<LitrePerHundredKilometre>() - 5.880365).abs() < 1e-5);
+    }
+}
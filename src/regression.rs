@@ -0,0 +1,351 @@
+use std::{
+    marker::PhantomData,
+    ops::{
+        Div,
+        Mul,
+    },
+};
+
+use num::{
+    traits::{
+        ConstOne,
+        ConstZero,
+    },
+    Float,
+};
+
+use crate::{
+    dimension::{
+        DimInverse,
+        DimPow,
+        Dimension,
+    },
+    kind::KindDiv,
+    matrix::solve_rows,
+    polynomial::{
+        Coefficient,
+        CoefficientDim,
+        Polynomial,
+    },
+    quantity::{
+        Derivative,
+        Quantity,
+        QuantityType,
+    },
+    si::Unitless,
+    value_type::{
+        RealValueType,
+        ValueType,
+    },
+};
+
+/// A least squares fit of `Y` against `X` with a straight line.
+#[derive(Debug, Clone)]
+pub struct LinearFit<X, Y>
+where
+    X: QuantityType,
+{
+    fit: PolynomialFit<X, Y>,
+}
+
+impl<X, Y> LinearFit<X, Y>
+where
+    X: QuantityType,
+    X::DataType: RealValueType + Float,
+    Y: QuantityType<DataType = X::DataType>,
+{
+    /// Slope of the line, e.g. a velocity when fitting a length against time
+    pub fn slope(&self) -> Derivative<X, Y>
+    where
+        Y::Dimension: Div<X::Dimension>,
+        <Y::Dimension as Div<X::Dimension>>::Output: Dimension,
+        Y::Kind: KindDiv<X::Kind>,
+    {
+        Quantity::new_base(self.fit.coefficients[1])
+    }
+
+    /// Value of the line at `x = 0`
+    pub fn intercept(&self) -> Y {
+        self.fit.intercept()
+    }
+
+    /// Coefficient of determination
+    pub fn r_squared(&self) -> Unitless<X::DataType> {
+        self.fit.r_squared()
+    }
+
+    /// Differences between the measured values and the line
+    pub fn residuals(&self) -> Vec<Quantity<X::DataType, Y::Dimension, ()>> {
+        self.fit.residuals()
+    }
+
+    /// Evaluate the line at `x`
+    pub fn eval(&self, x: X) -> Y {
+        self.fit.eval(x)
+    }
+}
+
+/// A least squares fit of `Y` against `X` with a polynomial.
+///
+/// The coefficient of degree `P` has the type `Y / X^P`, use `coefficient` to get it.
+#[derive(Debug, Clone)]
+pub struct PolynomialFit<X, Y>
+where
+    X: QuantityType,
+{
+    coefficients: Vec<X::DataType>,
+    r_squared: X::DataType,
+    residuals: Vec<X::DataType>,
+    _types: PhantomData<(X, Y)>,
+}
+
+impl<X, Y> PolynomialFit<X, Y>
+where
+    X: QuantityType,
+    X::DataType: RealValueType + Float,
+    Y: QuantityType<DataType = X::DataType>,
+{
+    /// Degree of the fitted polynomial
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    /// The coefficient of degree `P`, where `P` is a typenum integer, e.g. `typenum::P2`.
+    ///
+    /// Returns `None` if `P` is larger than the degree of the fit.
+    pub fn coefficient<P>(&self) -> Option<Coefficient<X, Y, P>>
+    where
+        P: typenum::Integer,
+        X::Dimension: DimPow<P>,
+        <X::Dimension as DimPow<P>>::Output: DimInverse,
+        Y::Dimension: Mul<<<X::Dimension as DimPow<P>>::Output as DimInverse>::Output>,
+        CoefficientDim<X, Y, P>: Dimension,
+    {
+        usize::try_from(P::to_i32())
+            .ok()
+            .and_then(|p| self.coefficients.get(p))
+            .map(|c| Quantity::new_base(*c))
+    }
+
+    /// The fitted polynomial
+    pub fn polynomial(&self) -> Polynomial<X, Y> {
+        Polynomial::from_base(self.coefficients.clone())
+    }
+
+    /// The coefficient of degree zero, the value at `x = 0`
+    pub fn intercept(&self) -> Y {
+        Y::from_base(self.coefficients[0])
+    }
+
+    /// Coefficient of determination
+    pub fn r_squared(&self) -> Unitless<X::DataType> {
+        Quantity::new_base(self.r_squared)
+    }
+
+    /// Differences between the measured values and the polynomial
+    pub fn residuals(&self) -> Vec<Quantity<X::DataType, Y::Dimension, ()>> {
+        self.residuals
+            .iter()
+            .map(|r| Quantity::new_base(*r))
+            .collect()
+    }
+
+    /// Evaluate the polynomial at `x`
+    pub fn eval(&self, x: X) -> Y {
+        self.polynomial().eval(x)
+    }
+}
+
+fn horner<T>(coefficients: &[T], x: T) -> T
+where
+    T: ValueType,
+{
+    coefficients
+        .iter()
+        .rev()
+        .fold(T::ZERO, |acc, c| acc * x + *c)
+}
+
+/// Fit a straight line through the points using least squares.
+///
+/// Returns `None` if `xs` and `ys` differ in length, there are less than two points, or all `xs` are equal.
+pub fn linear_fit<X, Y>(xs: &[X], ys: &[Y]) -> Option<LinearFit<X, Y>>
+where
+    X: QuantityType,
+    X::DataType: RealValueType + Float,
+    Y: QuantityType<DataType = X::DataType>,
+{
+    Some(LinearFit {
+        fit: polynomial_fit(xs, ys, 1)?,
+    })
+}
+
+/// Fit a polynomial of the given degree through the points using least squares.
+///
+/// Returns `None` if `xs` and `ys` differ in length, there are not more points than the degree,
+/// or the points do not determine the polynomial.
+pub fn polynomial_fit<X, Y>(xs: &[X], ys: &[Y], degree: usize) -> Option<PolynomialFit<X, Y>>
+where
+    X: QuantityType,
+    X::DataType: RealValueType + Float,
+    Y: QuantityType<DataType = X::DataType>,
+{
+    if xs.len() != ys.len() || xs.len() <= degree {
+        return None;
+    }
+    let x: Vec<X::DataType> = xs.iter().map(|x| x.base_value()).collect();
+    let y: Vec<X::DataType> = ys.iter().map(|y| y.base_value()).collect();
+
+    // Centre and scale x to about one, the normal equations are badly conditioned otherwise
+    let (low, high) = x
+        .iter()
+        .fold((x[0], x[0]), |(low, high), x| (low.min(*x), high.max(*x)));
+    let centre = x.iter().fold(X::DataType::ZERO, |acc, x| acc + *x)
+        / X::DataType::new_from_real_f64(x.len() as f64);
+    let range = if high > low {
+        high - low
+    } else {
+        X::DataType::ONE
+    };
+    let scaled: Vec<X::DataType> = x.iter().map(|x| (*x - centre) / range).collect();
+
+    // Normal equations, a[i][j] = sum u^(i+j), b[i] = sum y u^i
+    let n = degree + 1;
+    let mut a = vec![vec![X::DataType::ZERO; n]; n];
+    let mut b = vec![[X::DataType::ZERO]; n];
+    for (ui, yi) in scaled.iter().zip(y.iter()) {
+        let powers: Vec<X::DataType> = (0..2 * n - 1)
+            .scan(X::DataType::ONE, |p, _| {
+                let current = *p;
+                *p = *p * *ui;
+                Some(current)
+            })
+            .collect();
+        for (i, row) in a.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = *value + powers[i + j];
+            }
+            b[i][0] = b[i][0] + *yi * powers[i];
+        }
+    }
+    if !solve_rows(&mut a, &mut b) {
+        return None;
+    }
+    let centred: Vec<X::DataType> = b.iter().map(|[c]| *c).collect();
+
+    // Expand sum d_k ((x - centre) / range)^k into powers of x
+    let mut coefficients = vec![X::DataType::ZERO; n];
+    for d in centred.iter().rev() {
+        let mut carry = X::DataType::ZERO;
+        for c in coefficients.iter_mut() {
+            let shifted = (carry - centre * *c) / range;
+            carry = *c;
+            *c = shifted;
+        }
+        coefficients[0] = coefficients[0] + *d;
+    }
+
+    let residuals: Vec<X::DataType> = scaled
+        .iter()
+        .zip(y.iter())
+        .map(|(u, y)| *y - horner(&centred, *u))
+        .collect();
+    let mean = y.iter().fold(X::DataType::ZERO, |acc, y| acc + *y)
+        / X::DataType::new_from_real_f64(y.len() as f64);
+    let ss_tot = y
+        .iter()
+        .fold(X::DataType::ZERO, |acc, y| acc + (*y - mean) * (*y - mean));
+    let ss_res = residuals
+        .iter()
+        .fold(X::DataType::ZERO, |acc, r| acc + *r * *r);
+    let r_squared = if ss_tot == X::DataType::ZERO {
+        X::DataType::ONE
+    } else {
+        X::DataType::ONE - ss_res / ss_tot
+    };
+
+    Some(PolynomialFit {
+        coefficients,
+        r_squared,
+        residuals,
+        _types: PhantomData,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::si::*;
+
+    #[test]
+    fn calibration_line() {
+        let times = [0.0, 1.0, 2.0, 3.0].map(Time64::new::<Second>);
+        let lengths = [1.0, 3.1, 4.9, 7.0].map(Length64::new::<Metre>);
+
+        let fit = linear_fit(&times, &lengths).unwrap();
+        let v: Velocity64 = fit.slope();
+        let l: Length64 = fit.intercept();
+        assert!((v.get::<MetresPerSecond>() - 1.98).abs() < 1e-9);
+        assert!((l.get::<Metre>() - 1.03).abs() < 1e-9);
+        assert!(fit.r_squared().base_value() > 0.99);
+        assert_eq!(fit.residuals().len(), 4);
+        assert!((fit.residuals()[0].get::<Metre>() + 0.03).abs() < 1e-9);
+
+        assert!(linear_fit(&times[..1], &lengths[..1]).is_none());
+        assert!(linear_fit(&times, &lengths[..3]).is_none());
+    }
+
+    #[test]
+    fn polynomial() {
+        // s = 2 m + 3 m/s t + 0.5 m/s² t²
+        let times = [0.0, 1.0, 2.0, 3.0, 4.0].map(Time64::new::<Second>);
+        let lengths = times.map(|t| {
+            let t = t.get::<Second>();
+            Length64::new::<Metre>(2.0 + 3.0 * t + 0.5 * t * t)
+        });
+
+        let fit = polynomial_fit(&times, &lengths, 2).unwrap();
+        assert_eq!(fit.degree(), 2);
+        let a: Acceleration64 = fit.coefficient::<typenum::P2>().unwrap();
+        assert!((a.get::<MetresPerSecondSquared>() - 0.5).abs() < 1e-9);
+        let v: Velocity64 = fit.coefficient::<typenum::P1>().unwrap();
+        assert!((v.get::<MetresPerSecond>() - 3.0).abs() < 1e-9);
+        assert!(fit.coefficient::<typenum::P3>().is_none());
+        assert!((fit.r_squared().base_value() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn offset_x() {
+        // timestamps are far from zero compared to their spread
+        let times: Vec<Time64> = (0..10)
+            .map(|i| Time64::new::<Second>(1.7e9 + f64::from(i)))
+            .collect();
+        let lengths: Vec<Length64> = times
+            .iter()
+            .map(|t| Length64::new::<Metre>(2.0 * (t.get::<Second>() - 1.7e9) + 1.0))
+            .collect();
+        let fit = linear_fit(&times, &lengths).unwrap();
+        assert!((fit.slope().get::<MetresPerSecond>() - 2.0).abs() < 1e-6);
+        assert!(fit
+            .residuals()
+            .iter()
+            .all(|r| r.get::<Metre>().abs() < 1e-6));
+
+        // s = 1 m + 0.1 m/s³ (t - 1005 s)³
+        let times: Vec<Time64> = (0..10)
+            .map(|i| Time64::new::<Second>(1000.0 + f64::from(i)))
+            .collect();
+        let lengths: Vec<Length64> = times
+            .iter()
+            .map(|t| Length64::new::<Metre>(1.0 + 0.1 * (t.get::<Second>() - 1005.0).powi(3)))
+            .collect();
+        let fit = polynomial_fit(&times, &lengths, 3).unwrap();
+        assert!(fit
+            .residuals()
+            .iter()
+            .all(|r| r.get::<Metre>().abs() < 1e-9));
+        assert!((fit.r_squared().base_value() - 1.0).abs() < 1e-12);
+        let jerk: Quantity<f64, _, ()> = fit.coefficient::<typenum::P3>().unwrap();
+        assert!((jerk.base_value() - 0.1).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,268 @@
+use std::ops::Div;
+
+use num::{
+    traits::ConstZero,
+    Float,
+    Zero,
+};
+
+use crate::{
+    dimension::Dimension,
+    kind::KindDiv,
+    quantity::{
+        Derivative,
+        QuantityType,
+    },
+    value_type::{
+        RealValueType,
+        ValueType,
+    },
+};
+
+/// Maximum number of iterations the solvers in this module do before giving up
+const MAX_ITERATIONS: usize = 1000;
+
+/// Find a root of `f` between `a` and `b` using bisection.
+///
+/// `f(a)` and `f(b)` have to differ in sign. Iteration stops once the bracket is smaller than `tolerance`.
+/// Returns `None` if the bracket does not contain a sign change.
+pub fn bisection<X, Y, F>(f: F, a: X, b: X, tolerance: X) -> Option<X>
+where
+    X: QuantityType,
+    X::DataType: RealValueType + Float,
+    Y: QuantityType<DataType = X::DataType>,
+    F: Fn(X) -> Y,
+{
+    let eval = |x| f(X::from_base(x)).base_value();
+    let tolerance = tolerance.base_value().abs();
+    let two = X::DataType::new_from_real_f64(2.0);
+    let (mut a, mut b) = (a.base_value(), b.base_value());
+    let mut fa = eval(a);
+    let fb = eval(b);
+    if fa.is_zero() {
+        return Some(X::from_base(a));
+    }
+    if fb.is_zero() {
+        return Some(X::from_base(b));
+    }
+    if fa.signum() == fb.signum() {
+        return None;
+    }
+
+    for _ in 0..MAX_ITERATIONS {
+        let m = a + (b - a) / two;
+        if (b - a).abs() <= tolerance {
+            return Some(X::from_base(m));
+        }
+        let fm = eval(m);
+        if fm.is_zero() {
+            return Some(X::from_base(m));
+        }
+        if fm.signum() == fa.signum() {
+            a = m;
+            fa = fm;
+        } else {
+            b = m;
+        }
+    }
+    None
+}
+
+/// Find a root of `f` between `a` and `b` using Brent's method.
+///
+/// This combines bisection with the secant method and inverse quadratic interpolation,
+/// so it converges a lot faster than pure bisection while being just as robust.
+/// `f(a)` and `f(b)` have to differ in sign.
+/// Returns `None` if the bracket does not contain a sign change, or the method does not converge.
+pub fn brent<X, Y, F>(f: F, a: X, b: X, tolerance: X) -> Option<X>
+where
+    X: QuantityType,
+    X::DataType: RealValueType + Float,
+    Y: QuantityType<DataType = X::DataType>,
+    F: Fn(X) -> Y,
+{
+    let eval = |x| f(X::from_base(x)).base_value();
+    let tolerance = tolerance.base_value().abs();
+    let (two, three, four) = (
+        X::DataType::new_from_real_f64(2.0),
+        X::DataType::new_from_real_f64(3.0),
+        X::DataType::new_from_real_f64(4.0),
+    );
+    let (mut a, mut b) = (a.base_value(), b.base_value());
+    let (mut fa, mut fb) = (eval(a), eval(b));
+    if fa.is_zero() {
+        return Some(X::from_base(a));
+    }
+    if fa.signum() == fb.signum() && !fb.is_zero() {
+        return None;
+    }
+    if fa.abs() < fb.abs() {
+        std::mem::swap(&mut a, &mut b);
+        std::mem::swap(&mut fa, &mut fb);
+    }
+
+    let (mut c, mut fc) = (a, fa);
+    let mut d = c;
+    let mut bisected = true;
+    for _ in 0..MAX_ITERATIONS {
+        if fb.is_zero() || (b - a).abs() <= tolerance {
+            return Some(X::from_base(b));
+        }
+
+        let mut s = if fa != fc && fb != fc {
+            // inverse quadratic interpolation
+            a * fb * fc / ((fa - fb) * (fa - fc))
+                + b * fa * fc / ((fb - fa) * (fb - fc))
+                + c * fa * fb / ((fc - fa) * (fc - fb))
+        } else {
+            // secant
+            b - fb * (b - a) / (fb - fa)
+        };
+
+        let bound = (three * a + b) / four;
+        let outside = (s - bound) * (s - b) >= X::DataType::ZERO;
+        let slow = if bisected {
+            (s - b).abs() >= (b - c).abs() / two || (b - c).abs() < tolerance
+        } else {
+            (s - b).abs() >= (c - d).abs() / two || (c - d).abs() < tolerance
+        };
+        bisected = outside || slow;
+        if bisected {
+            s = (a + b) / two;
+        }
+
+        let fs = eval(s);
+        d = c;
+        c = b;
+        fc = fb;
+        if fa.signum() != fs.signum() {
+            b = s;
+            fb = fs;
+        } else {
+            a = s;
+            fa = fs;
+        }
+        if fa.abs() < fb.abs() {
+            std::mem::swap(&mut a, &mut b);
+            std::mem::swap(&mut fa, &mut fb);
+        }
+    }
+    None
+}
+
+/// Find a root of `f` using Newton's method, starting at `x0`.
+///
+/// `df` is the derivative of `f`, so for `f` yielding a power over a resistance, `df` yields watts per ohm.
+/// Iteration stops once a step is smaller than `tolerance`.
+/// Returns `None` if the derivative vanishes or the method does not converge.
+pub fn newton<X, Y, F, DF>(f: F, df: DF, x0: X, tolerance: X) -> Option<X>
+where
+    X: QuantityType,
+    X::DataType: RealValueType + Float,
+    Y: QuantityType<DataType = X::DataType>,
+    Y::Dimension: Div<X::Dimension>,
+    <Y::Dimension as Div<X::Dimension>>::Output: Dimension,
+    Y::Kind: KindDiv<X::Kind>,
+    F: Fn(X) -> Y,
+    DF: Fn(X) -> Derivative<X, Y>,
+{
+    let tolerance = tolerance.base_value().abs();
+    let mut x = x0.base_value();
+    for _ in 0..MAX_ITERATIONS {
+        let slope = df(X::from_base(x)).base_value();
+        if slope.is_zero() || !slope.is_finite() {
+            return None;
+        }
+        let step = f(X::from_base(x)).base_value() / slope;
+        x = x - step;
+        if !x.is_finite() {
+            return None;
+        }
+        if step.abs() <= tolerance {
+            return Some(X::from_base(x));
+        }
+    }
+    None
+}
+
+/// Find the minimum of `f` between `a` and `b` using golden section search.
+///
+/// `f` has to be unimodal on the interval for this to find the global minimum.
+/// Iteration stops once the remaining interval is smaller than `tolerance`.
+pub fn golden_section_min<X, Y, F>(f: F, a: X, b: X, tolerance: X) -> X
+where
+    X: QuantityType,
+    X::DataType: RealValueType + Float,
+    Y: QuantityType<DataType = X::DataType>,
+    F: Fn(X) -> Y,
+{
+    let eval = |x| f(X::from_base(x)).base_value();
+    let tolerance = tolerance.base_value().abs();
+    let inv_phi = X::DataType::new_from_real_f64((5.0f64.sqrt() - 1.0) / 2.0);
+    let (mut a, mut b) = (a.base_value(), b.base_value());
+
+    let mut c = b - (b - a) * inv_phi;
+    let mut d = a + (b - a) * inv_phi;
+    let (mut fc, mut fd) = (eval(c), eval(d));
+    for _ in 0..MAX_ITERATIONS {
+        if (b - a).abs() <= tolerance {
+            break;
+        }
+        if fc < fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - (b - a) * inv_phi;
+            fc = eval(c);
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + (b - a) * inv_phi;
+            fd = eval(d);
+        }
+    }
+    X::from_base((a + b) / X::DataType::new_from_real_f64(2.0))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::si::*;
+
+    #[test]
+    fn resistance_for_power() {
+        // Which resistance dissipates 10 mW at 1 V?
+        let v = ElectricPotential64::new::<Volt>(1.0);
+        let target = Power64::new::<MilliWatt>(10.0);
+        let f = |r: ElectricResistance64| v * v / r - target;
+        let lo = ElectricResistance64::new::<Ohm>(1.0);
+        let hi = ElectricResistance64::new::<KiloOhm>(1.0);
+        let tol = ElectricResistance64::new::<MilliOhm>(1.0);
+
+        let r = bisection(f, lo, hi, tol).expect("bracketed");
+        assert!((r.get::<Ohm>() - 100.0).abs() < 1e-3);
+        let r = brent(f, lo, hi, tol).expect("bracketed");
+        assert!((r.get::<Ohm>() - 100.0).abs() < 1e-3);
+        assert!(brent(f, hi, hi * 2.0, tol).is_none());
+
+        let df = |r: ElectricResistance64| -(v * v) / (r * r);
+        let r = newton(f, df, ElectricResistance64::new::<Ohm>(50.0), tol).expect("converges");
+        assert!((r.get::<Ohm>() - 100.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn golden_section() {
+        let f = |x: Length64| {
+            let d = x - Length::new::<Metre>(2.0);
+            d * d
+        };
+        let x = golden_section_min(
+            f,
+            Length::new::<Metre>(0.0),
+            Length::new::<Metre>(5.0),
+            Length::new::<MicroMetre>(1.0),
+        );
+        assert!((x.get::<Metre>() - 2.0).abs() < 1e-6);
+    }
+}
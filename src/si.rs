@@ -5,20 +5,34 @@ use crate::{
     derive_quantities,
     dimension::{
         AmountDim,
+        AreaDim,
         CurrentDim,
+        EnergyDim,
         IntensityDim,
         LengthDim,
         MassDim,
         NoDim,
+        PowerDim,
+        PressureDim,
         TemperatureDim,
         TimeDim,
+        VolumeDim,
+    },
+    kind::{
+        AngleKind,
+        KindDiv,
     },
-    kind::AngleKind,
     make_base_quantities,
     make_kind,
     make_quantity,
     make_units,
-    quantity::Quantity,
+    nonlinear_unit,
+    quantity::{
+        Quantity,
+        QuantityType,
+    },
+    register_unit,
+    value_type::ValueType,
 };
 
 make_units!(
@@ -40,6 +54,9 @@ make_base_quantities!(
     LouminousIntensity: IntensityDim, Candela;
 );
 
+// Gram is a prefixed kilogram, so no quantity macro declares it
+register_unit!(Mass, Gram);
+
 make_quantity!(
     name: ThermodynamicTemperature,
     dimension: TemperatureDim,
@@ -68,12 +85,18 @@ pub type UnitlessC64 = Unitless<Complex<f64>>;
 
 make_units!(
     Ratio: "", "A unitless ratio";
+    Percent: "%", "A unitless ratio of one part per hundred";
+    Permille: "‰", "A unitless ratio of one part per thousand";
+    PartsPerMillion: "ppm", "A unitless ratio of one part per million";
+    PartsPerBillion: "ppb", "A unitless ratio of one part per billion";
     Gram: "g", "Unit of mass", prefix_units=[KgSpecialCase];
     Hertz: "Hz", "Unit of frequency",prefix_units=[AllPrefixes];
     Radian: "rad", "Unit of angle";
     Degree: "°", "Alternate unit of angle";
     Minute: "min", "Alternate unit of time";
     Hour: "hr", "Alternate unit of time";
+    Day: "d", "Alternate unit of time";
+    Week: "wk", "Alternate unit of time";
     RadianPerSecond: "rad*s⁻¹", "Unit of angular velocity and a unit of angular frequency";
     RevolutionsPerSecond: "rps", "A unit of angular velocity";
     RevolutionsPerMinute: "rpm", "A unit of angular velocity";
@@ -98,6 +121,8 @@ make_units!(
     NewtonPerSquareMilliMetre: "N*mm⁻²", "Alternate unit of pressure";
     Joule: "J", "Unit of energy",prefix_units=[AllPrefixes];
     Watt: "W", "Unit of power",prefix_units=[AllPrefixes];
+    VoltAmpereReactive: "var", "Unit of reactive power",prefix_units=[AllPrefixes];
+    VoltAmpere: "VA", "Unit of apparent power",prefix_units=[AllPrefixes];
     Coulomb: "C", "Unit of charge",prefix_units=[AllPrefixes];
     Volt: "V", "Unit of electric potential",prefix_units=[AllPrefixes];
     Farad: "F", "Unit of electrical capacitance",prefix_units=[AllPrefixes];
@@ -120,15 +145,95 @@ make_units!(
     SquareMetrePerSecond: "m²*s⁻¹", "Unit of kinematic viscosity";
     PascalSecond: "Pa*s", "Unit of dynamic viscosity",prefix_units=[AllPrefixes];
     OnePerKelvin: "K⁻¹", "Temperature coefficient";
+    PercentPerKelvin: "%*K⁻¹", "Alternate unit of temperature coefficient";
+    PartsPerMillionPerKelvin: "ppm*K⁻¹", "Alternate unit of temperature coefficient";
+    Steradian: "sr", "Unit of solid angle";
+    Lumen: "lm", "Unit of luminous flux",prefix_units=[AllPrefixes];
+    Lux: "lx", "Unit of illuminance",prefix_units=[AllPrefixes];
+    CandelaPerSquareMetre: "cd*m⁻²", "Unit of luminance";
+    LumenPerWatt: "lm*W⁻¹", "Unit of luminous efficacy";
+    WattPerSteradian: "W*sr⁻¹", "Unit of radiant intensity";
+    WattPerSteradianPerSquareMetre: "W*sr⁻¹*m⁻²", "Unit of radiance";
+    PerMole: "mol⁻¹", "Unit of the Avogadro constant";
+    KilogramPerMole: "kg*mol⁻¹", "Unit of molar mass";
+    GramPerMole: "g*mol⁻¹", "Alternate unit of molar mass";
+    MolePerCubicMetre: "mol*m⁻³", "Unit of molar concentration";
+    MolePerLitre: "mol*L⁻¹", "Alternate unit of molar concentration";
+    Molar: "M", "Alternate unit of molar concentration, one mole per litre",prefix_units=[AllPrefixes];
+    MolePerKilogram: "mol*kg⁻¹", "Unit of molality";
+    CubicMetrePerMole: "m³*mol⁻¹", "Unit of molar volume";
+    LitrePerMole: "L*mol⁻¹", "Alternate unit of molar volume";
+    JoulePerMole: "J*mol⁻¹", "Unit of molar energy",prefix_units=[AllPrefixes];
+    JoulePerMoleKelvin: "J*mol⁻¹*K⁻¹", "Unit of molar heat capacity and molar entropy";
+    Katal: "kat", "Unit of catalytic activity",prefix_units=[AllPrefixes];
+    JoulePerKelvin: "J*K⁻¹", "Unit of heat capacity and entropy",prefix_units=[AllPrefixes];
+    JoulePerKilogramKelvin: "J*kg⁻¹*K⁻¹", "Unit of specific heat capacity",prefix_units=[AllPrefixes];
+    WattPerMetreKelvin: "W*m⁻¹*K⁻¹", "Unit of thermal conductivity";
+    KelvinPerWatt: "K*W⁻¹", "Unit of thermal resistance";
+    WattPerSquareMetreKelvin: "W*m⁻²*K⁻¹", "Unit of heat transfer coefficient";
+    CubicMetrePerSecond: "m³*s⁻¹", "Unit of volumetric flow rate";
+    LitrePerSecond: "L*s⁻¹", "Alternate unit of volumetric flow rate";
+    LitrePerMinute: "L*min⁻¹", "Alternate unit of volumetric flow rate";
+    KilogramPerSecond: "kg*s⁻¹", "Unit of mass flow rate";
+    HenryPerMetre: "H*m⁻¹", "Unit of magnetic permeability",prefix_units=[AllPrefixes];
+    AmperePerMetre: "A*m⁻¹", "Unit of magnetic field strength",prefix_units=[AllPrefixes];
+    VoltPerMetre: "V*m⁻¹", "Unit of electric field strength",prefix_units=[AllPrefixes];
+    CoulombPerSquareMetre: "C*m⁻²", "Unit of electric displacement",prefix_units=[AllPrefixes];
+    CoulombPerCubicMetre: "C*m⁻³", "Unit of charge density",prefix_units=[AllPrefixes];
+    AmperePerSquareMetre: "A*m⁻²", "Unit of current density",prefix_units=[AllPrefixes];
+    OhmMetre: "Ω*m", "Unit of electrical resistivity",prefix_units=[AllPrefixes];
+    SiemensPerMetre: "S*m⁻¹", "Unit of electrical conductivity",prefix_units=[AllPrefixes];
+    WeberPerMetre: "Wb*m⁻¹", "Unit of magnetic vector potential",prefix_units=[AllPrefixes];
+    AmpereSquareMetre: "A*m²", "Unit of magnetic moment",prefix_units=[AllPrefixes];
+    MetreToTheFourth: "m⁴", "Unit of second moment of area";
+    NewtonMetrePerRadian: "N*m*rad⁻¹", "Unit of rotational stiffness",prefix_units=[AllPrefixes];
+    KilogramSquareMetre: "kg*m²", "Unit of moment of inertia";
+    KilogramPerSquareMetreSecond: "kg*m⁻²*s⁻¹", "Unit of momentum density";
+    AstronomicalUnit: "au", "Astronomical unit of length";
+    LightYear: "ly", "Astronomical unit of length";
+    Parsec: "pc", "Astronomical unit of length",prefix_units=[AllPrefixes];
+    SolarMass: "M☉", "Astronomical unit of mass";
+    EarthMass: "M⊕", "Astronomical unit of mass";
+    JulianYear: "a", "Astronomical unit of time, exactly 365.25 days";
+    Angstrom: "Å", "Atomic scale unit of length";
+    BohrRadius: "a₀", "Atomic unit of length";
+    Electronvolt: "eV", "Atomic scale unit of energy",prefix_units=[AllPrefixes];
+    Dalton: "Da", "Atomic scale unit of mass",prefix_units=[AllPrefixes];
+    UnifiedAtomicMassUnit: "u", "Atomic scale unit of mass, same as the dalton";
+    Barn: "b", "Unit of area for nuclear cross sections",prefix_units=[AllPrefixes];
+    LitrePerHundredKilometre: "L*(100km)⁻¹", "Unit of fuel consumption";
+    Litre: "L", "Alternate unit of volume",prefix_units=[AllPrefixes];
+    MilesPerGallon: "mpg", "Reciprocal unit of fuel consumption, in US gallons";
+    AmericanWireGauge: "AWG", "Logarithmic unit of wire diameter";
+    PH: "pH", "Logarithmic unit of hydrogen ion concentration";
+    BolometricMagnitude: "mag", "Logarithmic unit of irradiance from a star";
 
 );
 
 additional_unit!(ThermodynamicTemperature, Celsius, 1.0, 273.15);
 additional_unit!(ThermodynamicTemperature, Fahrenheit, 5.0 / 9.0, 459.67);
 additional_unit!(Unitless, Ratio, 1.0);
+additional_unit!(Unitless, Percent, 1e-2);
+additional_unit!(Unitless, Permille, 1e-3);
+additional_unit!(Unitless, PartsPerMillion, 1e-6);
+additional_unit!(Unitless, PartsPerBillion, 1e-9);
 
 additional_unit!(Time, Minute, 60.0);
 additional_unit!(Time, Hour, 60.0 * 60.0);
+additional_unit!(Time, Day, 24.0 * 60.0 * 60.0);
+additional_unit!(Time, Week, 7.0 * 24.0 * 60.0 * 60.0);
+additional_unit!(Time, JulianYear, 365.25 * 24.0 * 60.0 * 60.0);
+
+additional_unit!(Length, AstronomicalUnit, 149_597_870_700.0);
+additional_unit!(Length, LightYear, 9_460_730_472_580_800.0);
+additional_unit!(Length, Parsec, 3.085_677_581_491_367e16);
+additional_unit!(Length, Angstrom, 1e-10);
+additional_unit!(Length, BohrRadius, 5.291_772_109_03e-11);
+
+additional_unit!(Mass, SolarMass, 1.988_47e30);
+additional_unit!(Mass, EarthMass, 5.972_2e24);
+additional_unit!(Mass, Dalton, 1.660_539_066_60e-27);
+additional_unit!(Mass, UnifiedAtomicMassUnit, 1.660_539_066_60e-27);
 
 make_quantity!(
     name: Angle,
@@ -139,6 +244,40 @@ make_quantity!(
 
 additional_unit!(Angle, Degree, std::f64::consts::PI / 180.0);
 
+make_quantity!(
+    name: SolidAngle,
+    dimension: NoDim,
+    base_unit: Steradian,
+    use_kind: SolidAngleKind
+);
+
+make_kind!(
+    name: SolidAngleKind,
+    rules: [
+        (SolidAngleKind + -> SolidAngleKind),
+        (SolidAngleKind - -> SolidAngleKind),
+    ]
+);
+
+make_kind!(
+    name: PerSolidAngleKind,
+    rules: [
+        (PerSolidAngleKind + -> PerSolidAngleKind),
+        (PerSolidAngleKind - -> PerSolidAngleKind),
+        (PerSolidAngleKind * SolidAngleKind -> ()),
+    ]
+);
+
+// Per steradian is the inverse of a solid angle, like W/sr for radiant intensity.
+// Multiplying by a solid angle again cancels it.
+impl KindDiv<SolidAngleKind> for () {
+    type Output = PerSolidAngleKind;
+}
+
+impl KindDiv<PerSolidAngleKind> for () {
+    type Output = SolidAngleKind;
+}
+
 derive_quantities!(
     Frequency: (Unitless / Time), Hertz, [
         RadianPerSecond: std::f64::consts::TAU;
@@ -172,6 +311,20 @@ derive_quantities!(
     MagneticInduction: (MagneticFlux / Area), Tesla;
     ElectricInducance: (ElectricResistance * Time), Henry;
     ElectricPermittivity: (Capacitance / Length), FaradPerMetre;
+    MagneticPermeability: (ElectricInducance / Length), HenryPerMetre;
+    MagneticFieldStrength: (ElectricCurrent / Length), AmperePerMetre;
+    ElectricFieldStrength: (ElectricPotential / Length), VoltPerMetre;
+    ElectricDisplacement: (ElectricCharge / Area), CoulombPerSquareMetre;
+    ChargeDensity: (ElectricCharge / Volume), CoulombPerCubicMetre;
+    CurrentDensity: (ElectricCurrent / Area), AmperePerSquareMetre;
+    Resistivity: (ElectricResistance * Length), OhmMetre;
+    Conductivity: (ElectricConductivity / Length), SiemensPerMetre;
+    MagneticVectorPotential: (MagneticFlux / Length), WeberPerMetre;
+    MagneticMoment: (ElectricCurrent * Area), AmpereSquareMetre;
+    SecondMomentOfArea: (Area * Area), MetreToTheFourth;
+    MomentOfInertia: (Mass * Area), KilogramSquareMetre;
+    Momentum: (Mass * Velocity), NewtonSecond;
+    MomentumDensity: (Momentum / Volume), KilogramPerSquareMetreSecond;
     AngularVelocity: (Angle/Time), RadianPerSecond, [
         Hertz: core::f64::consts::TAU;
         RevolutionsPerSecond: core::f64::consts::TAU;
@@ -190,13 +343,354 @@ derive_quantities!(
     HeatFluxDensity: (Power/Area), WattPerSquareMetre;
     KinematicViscosity: (Area/Time), SquareMetrePerSecond;
     DynamicViscosity: (Pressure * Time), PascalSecond;
-    TemperatureCoefficient: (Unitless / TemperatureInterval), OnePerKelvin;
+    TemperatureCoefficient: (Unitless / TemperatureInterval), OnePerKelvin, [
+        PercentPerKelvin: 1e-2;
+        PartsPerMillionPerKelvin: 1e-6;
+    ];
+    LuminousFlux: (LouminousIntensity * SolidAngle), Lumen;
+    Illuminance: (LuminousFlux / Area), Lux;
+    Luminance: (LouminousIntensity / Area), CandelaPerSquareMetre;
+    LuminousEfficacy: (LuminousFlux / Power), LumenPerWatt;
+    RadiantIntensity: (Power / SolidAngle), WattPerSteradian;
+    Radiance: (RadiantIntensity / Area), WattPerSteradianPerSquareMetre;
+);
+
+derive_quantities!(
+    InverseAmount: (Unitless / Amount), PerMole;
+    MolarMass: (Mass / Amount), KilogramPerMole, [
+        GramPerMole: 1e-3;
+    ];
+    MolarConcentration: (Amount / Volume), MolePerCubicMetre, [
+        MolePerLitre: 1e3;
+    ];
+    Molality: (Amount / Mass), MolePerKilogram;
+    MolarVolume: (Volume / Amount), CubicMetrePerMole, [
+        LitrePerMole: 1e-3;
+    ];
+    MolarEnergy: (Energy / Amount), JoulePerMole;
+    MolarHeatCapacity: (MolarEnergy / TemperatureInterval), JoulePerMoleKelvin;
+    CatalyticActivity: (Amount / Time), Katal;
+    HeatCapacity: (Energy / TemperatureInterval), JoulePerKelvin;
+    SpecificHeatCapacity: (HeatCapacity / Mass), JoulePerKilogramKelvin;
+    ThermalConductivity: (Power / Length / TemperatureInterval), WattPerMetreKelvin;
+    ThermalResistance: (TemperatureInterval / Power), KelvinPerWatt;
+    HeatTransferCoefficient: (Power / Area / TemperatureInterval), WattPerSquareMetreKelvin;
+    VolumetricFlowRate: (Volume / Time), CubicMetrePerSecond, [
+        LitrePerSecond: 1e-3;
+        LitrePerMinute: 1e-3 / 60.0;
+    ];
+    MassFlowRate: (Mass / Time), KilogramPerSecond;
+);
+
+/// Entropy shares its unit, J/K, with heat capacity
+pub type Entropy<T> = HeatCapacity<T>;
+pub type Entropy32 = Entropy<f32>;
+pub type Entropy64 = Entropy<f64>;
+pub type EntropyC32 = Entropy<Complex<f32>>;
+pub type EntropyC64 = Entropy<Complex<f64>>;
+
+// One molar is 1 mol/L, which is 1000 mol/m³
+additional_unit!(MolarConcentration, Molar, 1e3);
+
+/// Impulse is a change in momentum, in N·s
+pub type Impulse<T> = Momentum<T>;
+pub type Impulse32 = Impulse<f32>;
+pub type Impulse64 = Impulse<f64>;
+pub type ImpulseC32 = Impulse<Complex<f32>>;
+pub type ImpulseC64 = Impulse<Complex<f64>>;
+
+// Section modulus has the dimension of a volume, but is not one
+make_quantity!(
+    name: SectionModulus,
+    dimension: VolumeDim,
+    base_unit: CubicMetre,
+    use_kind: SectionModulusKind,
+);
+
+make_kind!(
+    name: SectionModulusKind,
+    rules: [
+        (SectionModulusKind + -> SectionModulusKind),
+        (SectionModulusKind - -> SectionModulusKind),
+    ]
+);
+
+// Bending stress is the bending moment over the section modulus, sigma = M / S
+impl KindDiv<SectionModulusKind> for AngleKind {
+    type Output = ();
+}
+
+/// Section modulus `I / c` of a beam, from the second moment of area
+/// and the distance of the outermost fibre from the neutral axis
+pub fn section_modulus<T>(
+    second_moment: Quantity<T, <SecondMomentOfArea64 as QuantityType>::Dimension, ()>,
+    distance: Length<T>,
+) -> SectionModulus<T>
+where
+    T: ValueType,
+{
+    SectionModulus::new_base(second_moment.base_value() / distance.base_value())
+}
+
+// Rotational stiffness has the dimension of an energy, but is torque per angle.
+// Torque is of angle kind, so the kinds cancel when dividing, use `rotational_stiffness` instead.
+make_quantity!(
+    name: RotationalStiffness,
+    dimension: EnergyDim,
+    base_unit: NewtonMetrePerRadian,
+    use_kind: RotationalStiffnessKind,
+);
+
+make_kind!(
+    name: RotationalStiffnessKind,
+    rules: [
+        (RotationalStiffnessKind + -> RotationalStiffnessKind),
+        (RotationalStiffnessKind - -> RotationalStiffnessKind),
+        (RotationalStiffnessKind * AngleKind -> AngleKind),
+    ]
+);
+
+// An angle of twist is the torque over the stiffness
+impl KindDiv<RotationalStiffnessKind> for AngleKind {
+    type Output = AngleKind;
+}
+
+/// Rotational stiffness of a spring that twists by `angle` under `torque`
+pub fn rotational_stiffness<T>(
+    torque: Quantity<T, <Torque64 as QuantityType>::Dimension, AngleKind>,
+    angle: Angle<T>,
+) -> RotationalStiffness<T>
+where
+    T: ValueType,
+{
+    RotationalStiffness::new_base(torque.base_value() / angle.base_value())
+}
+
+make_quantity!(
+    name: Strain,
+    dimension: NoDim,
+    base_unit: Ratio,
+    use_kind: StrainKind,
+);
+
+make_kind!(
+    name: StrainKind,
+    rules: [
+        (StrainKind + -> StrainKind),
+        (StrainKind - -> StrainKind),
+    ]
+);
+
+additional_unit!(Strain, Percent, 1e-2);
+additional_unit!(Strain, Permille, 1e-3);
+additional_unit!(Strain, PartsPerMillion, 1e-6);
+
+// Elastic moduli are measured in pascal, but are no pressure. Multiplied with a strain they give a stress.
+make_quantity!(
+    name: ElasticModulus,
+    dimension: PressureDim,
+    base_unit: Pascal,
+    use_kind: ElasticModulusKind,
+);
+
+make_kind!(
+    name: ElasticModulusKind,
+    rules: [
+        (ElasticModulusKind + -> ElasticModulusKind),
+        (ElasticModulusKind - -> ElasticModulusKind),
+        (ElasticModulusKind * StrainKind -> ()),
+    ]
+);
+
+// Dimensionless numbers of fluid mechanics. Each has its own kind, so they cannot be mixed up.
+
+make_quantity!(
+    name: ReynoldsNumber,
+    dimension: NoDim,
+    base_unit: Ratio,
+    use_kind: ReynoldsKind,
+);
+
+make_kind!(
+    name: ReynoldsKind,
+    rules: [
+        (ReynoldsKind + -> ReynoldsKind),
+        (ReynoldsKind - -> ReynoldsKind),
+    ]
+);
+
+make_quantity!(
+    name: PrandtlNumber,
+    dimension: NoDim,
+    base_unit: Ratio,
+    use_kind: PrandtlKind,
+);
+
+make_kind!(
+    name: PrandtlKind,
+    rules: [
+        (PrandtlKind + -> PrandtlKind),
+        (PrandtlKind - -> PrandtlKind),
+    ]
+);
+
+make_quantity!(
+    name: NusseltNumber,
+    dimension: NoDim,
+    base_unit: Ratio,
+    use_kind: NusseltKind,
+);
+
+make_kind!(
+    name: NusseltKind,
+    rules: [
+        (NusseltKind + -> NusseltKind),
+        (NusseltKind - -> NusseltKind),
+    ]
+);
+
+make_quantity!(
+    name: MachNumber,
+    dimension: NoDim,
+    base_unit: Ratio,
+    use_kind: MachKind,
+);
+
+make_kind!(
+    name: MachKind,
+    rules: [
+        (MachKind + -> MachKind),
+        (MachKind - -> MachKind),
+    ]
+);
+
+make_quantity!(
+    name: FroudeNumber,
+    dimension: NoDim,
+    base_unit: Ratio,
+    use_kind: FroudeKind,
+);
+
+make_kind!(
+    name: FroudeKind,
+    rules: [
+        (FroudeKind + -> FroudeKind),
+        (FroudeKind - -> FroudeKind),
+    ]
+);
+
+additional_unit!(Energy, Electronvolt, 1.602_176_634e-19);
+additional_unit!(Area, Barn, 1e-28);
+additional_unit!(Volume, Litre, 1e-3);
+
+/// Radiant flux is measured in watts, just like any other power
+pub type RadiantFlux<T> = Power<T>;
+pub type RadiantFlux32 = RadiantFlux<f32>;
+pub type RadiantFlux64 = RadiantFlux<f64>;
+pub type RadiantFluxC32 = RadiantFlux<Complex<f32>>;
+pub type RadiantFluxC64 = RadiantFlux<Complex<f64>>;
+
+/// Irradiance is a heat flux density, in W/m²
+pub type Irradiance<T> = HeatFluxDensity<T>;
+pub type Irradiance32 = Irradiance<f32>;
+pub type Irradiance64 = Irradiance<f64>;
+pub type IrradianceC32 = Irradiance<Complex<f32>>;
+pub type IrradianceC64 = Irradiance<Complex<f64>>;
+
+make_quantity!(
+    name: ReactivePower,
+    dimension: PowerDim,
+    base_unit: VoltAmpereReactive,
+    use_kind: ReactivePowerKind,
+);
+
+make_kind!(
+    name: ReactivePowerKind,
+    rules: [
+        (ReactivePowerKind + -> ReactivePowerKind),
+        (ReactivePowerKind - -> ReactivePowerKind),
+        (ReactivePowerKind / ApparentPowerKind -> ()),
+    ]
+);
+
+make_quantity!(
+    name: ApparentPower,
+    dimension: PowerDim,
+    base_unit: VoltAmpere,
+    use_kind: ApparentPowerKind,
+);
+
+make_kind!(
+    name: ApparentPowerKind,
+    rules: [
+        (ApparentPowerKind + -> ApparentPowerKind),
+        (ApparentPowerKind - -> ApparentPowerKind),
+    ]
+);
+
+// Real power over apparent power is the power factor, a plain ratio
+impl KindDiv<ApparentPowerKind> for () {
+    type Output = ();
+}
+
+// Units that do not convert linearly to their base unit
+
+make_quantity!(
+    name: FuelConsumption,
+    dimension: AreaDim,
+    base_unit: SquareMetre,
+    use_kind: FuelConsumptionKind,
+);
+
+make_kind!(
+    name: FuelConsumptionKind,
+    rules: [
+        (FuelConsumptionKind + -> FuelConsumptionKind),
+        (FuelConsumptionKind - -> FuelConsumptionKind),
+    ]
+);
+
+additional_unit!(FuelConsumption, LitrePerHundredKilometre, 1e-8);
+
+/// One US gallon per mile, in m²
+const GALLON_PER_MILE: f64 = 3.785_411_784e-3 / 1609.344;
+nonlinear_unit!(
+    FuelConsumption,
+    MilesPerGallon,
+    |mpg| GALLON_PER_MILE / mpg,
+    |base| GALLON_PER_MILE / base,
+);
+
+/// Diameter of AWG 36 wire; every 39 gauges the diameter changes by a factor of 92
+const AWG_36_DIAMETER: f64 = 0.127e-3;
+nonlinear_unit!(
+    Length,
+    AmericanWireGauge,
+    |gauge| AWG_36_DIAMETER * 92f64.powf((36.0 - gauge) / 39.0),
+    |base| 36.0 - 39.0 * (base / AWG_36_DIAMETER).log(92.0),
+);
+
+// pH is relative to 1 mol/L, which is 1000 mol/m³
+nonlinear_unit!(MolarConcentration, PH, |ph| 1e3 * 10f64.powf(-ph), |base| {
+    -(base / 1e3).log10()
+},);
+
+/// Irradiance of bolometric magnitude zero in W/m² (IAU 2015 B2)
+const MAGNITUDE_ZERO_IRRADIANCE: f64 = 2.518_021_002e-8;
+nonlinear_unit!(
+    Irradiance,
+    BolometricMagnitude,
+    |mag| MAGNITUDE_ZERO_IRRADIANCE * 10f64.powf(-0.4 * mag),
+    |base| -2.5 * (base / MAGNITUDE_ZERO_IRRADIANCE).log10(),
 );
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::quantity::QuantityType;
+    use crate::{
+        quantity::QuantityType,
+        unit::QuantityConversion,
+    };
 
     #[test]
     fn check_kind_conversion() {
@@ -237,10 +731,196 @@ mod test {
         assert_eq!("1.35 m", format!("{:.2}", dist.formatted::<Metre>()));
     }
 
+    #[test]
+    fn derived_dimensions() {
+        use typenum::{
+            assert_type_eq,
+            N1,
+            N2,
+            N3,
+            P1,
+            P2,
+            Z0,
+        };
+
+        use crate::dimension::SiDim;
+        assert_type_eq!(
+            <Acceleration64 as QuantityType>::Dimension,
+            SiDim<P1, Z0, N2, Z0, Z0, Z0, Z0>
+        );
+        assert_type_eq!(
+            <Force64 as QuantityType>::Dimension,
+            SiDim<P1, P1, N2, Z0, Z0, Z0, Z0>
+        );
+        assert_type_eq!(
+            <ElectricPotential64 as QuantityType>::Dimension,
+            SiDim<P2, P1, N3, N1, Z0, Z0, Z0>
+        );
+
+        // type_math! applies operators left to right, (Length / Time) / Time
+        type T = f64;
+        assert_type_eq!(crate::type_math!(Length / Time / Time), Acceleration64);
+        assert_type_eq!(crate::type_math!(Length / Time * Time), Length64);
+    }
+
     #[test]
     fn degree_rad() {
         let a = Angle::new::<Radian>(std::f64::consts::PI);
         let b = Angle::new::<Degree>(180.0);
         assert_eq!(a, b);
     }
+
+    #[test]
+    fn electromagnetic_fields() {
+        let e: ElectricFieldStrength64 =
+            ElectricPotential64::new::<Volt>(10.0) / Length64::new::<MilliMetre>(2.0);
+        assert_eq!(e.get::<KiloVoltPerMetre>(), 5.0);
+        let d: ElectricDisplacement64 = crate::constants::EPSILON_0 * e;
+        assert!((d.get::<CoulombPerSquareMetre>() - 4.427e-8).abs() < 1e-11);
+
+        // copper wire, 1 m long with 1 mm² cross section
+        let rho = Resistivity64::new::<NanoOhmMetre>(16.8);
+        let area = Area64::new::<SquareMilliMetre>(1.0);
+        let r: ElectricResistance64 = rho * Length64::new::<Metre>(1.0) / area;
+        assert!((r.get::<MilliOhm>() - 16.8).abs() < 1e-9);
+        let sigma: Conductivity64 = Unitless64::new::<Ratio>(1.0) / rho;
+        assert!((sigma.get::<MegaSiemensPerMetre>() - 59.52381).abs() < 1e-5);
+
+        let j: CurrentDensity64 = ElectricCurrent64::new::<Ampere>(2.0) / area;
+        assert_eq!(j.get::<AmperePerSquareMetre>(), 2e6);
+
+        let h = MagneticFieldStrength64::new::<KiloAmperePerMetre>(1.0);
+        let b: MagneticInduction64 = crate::constants::MU_0 * h;
+        assert!((b.get::<MilliTesla>() - 1.25663706212).abs() < 1e-9);
+
+        let m: MagneticMoment64 = ElectricCurrent64::new::<Ampere>(1.0) * area;
+        assert_eq!(m.get::<AmpereSquareMetre>(), 1e-6);
+        let a: MagneticVectorPotential64 =
+            MagneticFlux64::new::<Weber>(1.0) / Length64::new::<Metre>(4.0);
+        assert_eq!(a.get::<WeberPerMetre>(), 0.25);
+    }
+
+    #[test]
+    fn beam() {
+        let modulus = ElasticModulus64::new::<GigaPascal>(200.0);
+        let strain = Strain64::new::<Ratio>(1e-3);
+        let stress: Pressure64 = modulus * strain;
+        assert_eq!(stress.get::<MegaPascal>(), 200.0);
+        let stress: Pressure64 = strain * modulus;
+        assert_eq!(stress.get::<MegaPascal>(), 200.0);
+
+        // 100 mm square section
+        let side = Length64::new::<Metre>(0.1);
+        let i: SecondMomentOfArea64 = side * side * side * side / 12.0;
+        let s = section_modulus(i, side / 2.0);
+        assert!((s.get::<CubicMetre>() - 1.0 / 6.0 * 1e-3).abs() < 1e-15);
+
+        let torque = Torque64::new::<NewtonMetre>(10.0);
+        let angle = Angle64::new::<Radian>(0.5);
+        let stiffness = rotational_stiffness(torque, angle);
+        assert_eq!(stiffness.get::<NewtonMetrePerRadian>(), 20.0);
+        let twist: Angle64 = torque / stiffness;
+        assert_eq!(twist, angle);
+        let restoring: Torque64 = stiffness * angle;
+        assert_eq!(restoring, torque);
+
+        // bending stress sigma = M / S
+        let stress: Pressure64 = Torque64::new::<NewtonMetre>(1.0) / s;
+        assert!((stress.get::<KiloPascal>() - 6.0).abs() < 1e-9);
+
+        let inertia: MomentOfInertia64 =
+            Mass64::new::<Kilogram>(2.0) * Area64::new::<SquareMetre>(0.5);
+        assert_eq!(inertia.get::<KilogramSquareMetre>(), 1.0);
+
+        let impulse: Impulse64 = Force64::new::<Newton>(3.0) * Time64::new::<Second>(2.0);
+        let momentum: Momentum64 =
+            Mass64::new::<Kilogram>(2.0) * Velocity64::new::<MetresPerSecond>(3.0);
+        assert_eq!(impulse, momentum);
+        let density: MomentumDensity64 = momentum / Volume64::new::<CubicMetre>(2.0);
+        assert_eq!(density.get::<KilogramPerSquareMetreSecond>(), 3.0);
+    }
+
+    #[test]
+    fn astronomical_and_atomic() {
+        let pc = Length64::new::<Parsec>(1.0);
+        assert!((pc.get::<LightYear>() - 3.2615638).abs() < 1e-6);
+        assert!((pc.get::<AstronomicalUnit>() - 206264.806).abs() < 1e-3);
+        assert!((Length64::new::<MegaParsec>(1.0).get::<Parsec>() - 1e6).abs() < 1e-6);
+        let year = Time64::new::<JulianYear>(1.0);
+        let light_year: Length64 = Velocity64::new::<MetresPerSecond>(299_792_458.0) * year;
+        assert!((light_year.get::<LightYear>() - 1.0).abs() < 1e-12);
+        assert!((Mass64::new::<SolarMass>(1.0).get::<EarthMass>() - 332_954.355).abs() < 1e-3);
+
+        assert!((Length64::new::<Angstrom>(1.0).get::<NanoMetre>() - 0.1).abs() < 1e-12);
+        assert!((Length64::new::<BohrRadius>(1.0).get::<Angstrom>() - 0.529177).abs() < 1e-6);
+        let rest_energy = Energy64::new::<MegaElectronvolt>(938.272);
+        assert!((rest_energy.get::<GigaElectronvolt>() - 0.938272).abs() < 1e-12);
+        assert!((Energy64::new::<Electronvolt>(1.0).get::<MilliElectronvolt>() - 1e3).abs() < 1e-9);
+        let carbon = Mass64::new::<Dalton>(12.0);
+        assert_eq!(carbon.get::<UnifiedAtomicMassUnit>(), 12.0);
+        assert!((Area64::new::<Barn>(1.0).get::<SquareMetre>() - 1e-28).abs() < 1e-40);
+    }
+
+    #[test]
+    fn ratios() {
+        let tolerance = Unitless64::new::<Percent>(5.0);
+        assert_eq!(tolerance.get::<Ratio>(), 0.05);
+        assert!((tolerance.get::<Permille>() - 50.0).abs() < 1e-12);
+        assert_eq!(
+            format!(
+                "{:.2}",
+                Unitless64::new::<PartsPerBillion>(250.0).formatted::<PartsPerMillion>()
+            ),
+            "0.25 ppm"
+        );
+
+        let strain = Strain64::new::<PartsPerMillion>(1200.0);
+        assert!((strain.get::<Percent>() - 0.12).abs() < 1e-12);
+
+        let tc = TemperatureCoefficient64::new::<PartsPerMillionPerKelvin>(50.0);
+        assert_eq!(
+            format!("{}", tc.formatted::<PartsPerMillionPerKelvin>()),
+            "50 ppm*K⁻¹"
+        );
+        let drift: Unitless64 = tc * TemperatureInterval64::new::<Kelvin>(20.0);
+        assert!((drift.get::<Percent>() - 0.1).abs() < 1e-12);
+        assert!((tc.get::<PercentPerKelvin>() - 0.005).abs() < 1e-15);
+    }
+
+    #[test]
+    fn nonlinear_units() {
+        let consumption = FuelConsumption64::new::<MilesPerGallon>(30.0);
+        assert!((consumption.get::<LitrePerHundredKilometre>() - 7.840486).abs() < 1e-6);
+        let halved: FuelConsumption64 = consumption * 0.5;
+        assert!((halved.get::<MilesPerGallon>() - 60.0).abs() < 1e-12);
+
+        let wire = Length64::new::<AmericanWireGauge>(10.0);
+        assert!((wire.get::<MilliMetre>() - 2.588).abs() < 1e-3);
+        assert!(
+            (Length64::new::<MilliMetre>(0.127).get::<AmericanWireGauge>() - 36.0).abs() < 1e-12
+        );
+        assert!(
+            (Length64::new::<AmericanWireGauge>(-3.0).get::<AmericanWireGauge>() + 3.0).abs()
+                < 1e-12
+        );
+
+        let neutral = MolarConcentration64::new::<PH>(7.0);
+        assert!((neutral.get::<MicroMolar>() - 0.1).abs() < 1e-12);
+        assert!((MolarConcentration64::new::<Molar>(0.01).get::<PH>() - 2.0).abs() < 1e-12);
+
+        let star = Irradiance64::new::<BolometricMagnitude>(0.0);
+        let fainter: Irradiance64 = star / 100.0;
+        assert!((fainter.get::<BolometricMagnitude>() - 5.0).abs() < 1e-12);
+        assert_eq!(
+            format!("{:.1}", fainter.formatted::<BolometricMagnitude>()),
+            "5.0 mag"
+        );
+
+        type Dim = <MolarConcentration64 as QuantityType>::Dimension;
+        assert!(<PH as QuantityConversion<f64, Dim, ()>>::linear().is_none());
+        assert_eq!(
+            <Molar as QuantityConversion<f64, Dim, ()>>::linear(),
+            Some((1e3, 0.0))
+        );
+    }
 }
@@ -0,0 +1,312 @@
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    ops::Mul,
+};
+
+use num::Float;
+
+use crate::{
+    dimension::Dimension,
+    quantity::{
+        Quantity,
+        QuantityType,
+    },
+    value_type::{
+        RealValueType,
+        ValueType,
+    },
+};
+
+/// The variance of a quantity: its dimension squared, without kind.
+pub type Variance<T, D> = Quantity<T, <D as Mul<D>>::Output, ()>;
+
+/// Collect the base values of all quantities
+fn base_values<T, D, K, I>(values: I) -> Vec<T>
+where
+    T: ValueType,
+    D: Dimension,
+    I: IntoIterator,
+    I::Item: Borrow<Quantity<T, D, K>>,
+{
+    values
+        .into_iter()
+        .map(|q| q.borrow().base_value())
+        .collect()
+}
+
+/// Sum of squared deviations from the mean, and the number of values
+fn sum_of_squares<T, D, K, I>(values: I) -> Option<(T, usize)>
+where
+    T: ValueType + RealValueType,
+    D: Dimension,
+    I: IntoIterator,
+    I::Item: Borrow<Quantity<T, D, K>>,
+{
+    let values = base_values(values);
+    if values.is_empty() {
+        return None;
+    }
+    let n = T::new_from_real_f64(values.len() as f64);
+    let mean = values.iter().fold(T::ZERO, |acc, v| acc + *v) / n;
+    let sum = values
+        .iter()
+        .fold(T::ZERO, |acc, v| acc + (*v - mean) * (*v - mean));
+    Some((sum, values.len()))
+}
+
+/// Base values without NaN, sorted ascending
+fn sorted<T, D, K, I>(values: I) -> Vec<T>
+where
+    T: ValueType + RealValueType + Float,
+    D: Dimension,
+    I: IntoIterator,
+    I::Item: Borrow<Quantity<T, D, K>>,
+{
+    let mut values = base_values(values);
+    values.retain(|v| !v.is_nan());
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    values
+}
+
+/// Smallest or largest base value, ignoring NaN
+fn extreme<T, D, K, I>(values: I, pick: fn(T, T) -> T) -> Option<Quantity<T, D, K>>
+where
+    T: ValueType + RealValueType + Float,
+    D: Dimension,
+    I: IntoIterator,
+    I::Item: Borrow<Quantity<T, D, K>>,
+{
+    values
+        .into_iter()
+        .map(|q| q.borrow().base_value())
+        .filter(|v| !v.is_nan())
+        .reduce(pick)
+        .map(Quantity::new_base)
+}
+
+/// Arithmetic mean. Returns `None` if there are no values.
+///
+/// This is computed on base values, so it is also meaningful for absolute temperatures.
+pub fn mean<T, D, K, I>(values: I) -> Option<Quantity<T, D, K>>
+where
+    T: ValueType,
+    D: Dimension,
+    I: IntoIterator,
+    I::Item: Borrow<Quantity<T, D, K>>,
+{
+    let values = base_values(values);
+    if values.is_empty() {
+        return None;
+    }
+    let sum = values.iter().fold(T::ZERO, |acc, v| acc + *v);
+    Some(Quantity::new_base(
+        sum / T::new_from_real_f64(values.len() as f64),
+    ))
+}
+
+/// Population variance. Returns `None` if there are no values.
+pub fn variance<T, D, K, I>(values: I) -> Option<Variance<T, D>>
+where
+    T: ValueType + RealValueType,
+    D: Dimension + Mul<D>,
+    <D as Mul<D>>::Output: Dimension,
+    I: IntoIterator,
+    I::Item: Borrow<Quantity<T, D, K>>,
+{
+    let (sum, n) = sum_of_squares(values)?;
+    Some(Quantity::new_base(sum / T::new_from_real_f64(n as f64)))
+}
+
+/// Sample variance, using Bessel's correction. Returns `None` if there are less than two values.
+pub fn sample_variance<T, D, K, I>(values: I) -> Option<Variance<T, D>>
+where
+    T: ValueType + RealValueType,
+    D: Dimension + Mul<D>,
+    <D as Mul<D>>::Output: Dimension,
+    I: IntoIterator,
+    I::Item: Borrow<Quantity<T, D, K>>,
+{
+    let (sum, n) = sum_of_squares(values)?;
+    if n < 2 {
+        return None;
+    }
+    Some(Quantity::new_base(
+        sum / T::new_from_real_f64((n - 1) as f64),
+    ))
+}
+
+/// Population standard deviation. Returns `None` if there are no values.
+///
+/// A spread is a difference, so the kind is dropped: the deviation of absolute temperatures is a temperature interval.
+pub fn std_dev<T, D, K, I>(values: I) -> Option<Quantity<T, D, ()>>
+where
+    T: ValueType + RealValueType + Float,
+    D: Dimension,
+    I: IntoIterator,
+    I::Item: Borrow<Quantity<T, D, K>>,
+{
+    let (sum, n) = sum_of_squares(values)?;
+    Some(Quantity::new_base(
+        (sum / T::new_from_real_f64(n as f64)).sqrt(),
+    ))
+}
+
+/// Sample standard deviation, using Bessel's correction. Returns `None` if there are less than two values.
+pub fn sample_std_dev<T, D, K, I>(values: I) -> Option<Quantity<T, D, ()>>
+where
+    T: ValueType + RealValueType + Float,
+    D: Dimension,
+    I: IntoIterator,
+    I::Item: Borrow<Quantity<T, D, K>>,
+{
+    let (sum, n) = sum_of_squares(values)?;
+    if n < 2 {
+        return None;
+    }
+    Some(Quantity::new_base(
+        (sum / T::new_from_real_f64((n - 1) as f64)).sqrt(),
+    ))
+}
+
+/// Median, ignoring NaN. Returns `None` if there are no values.
+pub fn median<T, D, K, I>(values: I) -> Option<Quantity<T, D, K>>
+where
+    T: ValueType + RealValueType + Float,
+    D: Dimension,
+    I: IntoIterator,
+    I::Item: Borrow<Quantity<T, D, K>>,
+{
+    percentile(values, 50.0)
+}
+
+/// Percentile `p` in `0.0..=100.0`, interpolating linearly between the closest values.
+///
+/// NaN values are ignored. Returns `None` if there are no other values or `p` is out of range.
+pub fn percentile<T, D, K, I>(values: I, p: f64) -> Option<Quantity<T, D, K>>
+where
+    T: ValueType + RealValueType + Float,
+    D: Dimension,
+    I: IntoIterator,
+    I::Item: Borrow<Quantity<T, D, K>>,
+{
+    if !(0.0..=100.0).contains(&p) {
+        return None;
+    }
+    let values = sorted(values);
+    if values.is_empty() {
+        return None;
+    }
+    let rank = p / 100.0 * (values.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    let fraction = T::new_from_real_f64(rank - rank.floor());
+    Some(Quantity::new_base(
+        values[lower] + (values[upper] - values[lower]) * fraction,
+    ))
+}
+
+/// Smallest value, ignoring NaN. Returns `None` if there are no values.
+pub fn min<T, D, K, I>(values: I) -> Option<Quantity<T, D, K>>
+where
+    T: ValueType + RealValueType + Float,
+    D: Dimension,
+    I: IntoIterator,
+    I::Item: Borrow<Quantity<T, D, K>>,
+{
+    extreme(values, Float::min)
+}
+
+/// Largest value, ignoring NaN. Returns `None` if there are no values.
+pub fn max<T, D, K, I>(values: I) -> Option<Quantity<T, D, K>>
+where
+    T: ValueType + RealValueType + Float,
+    D: Dimension,
+    I: IntoIterator,
+    I::Item: Borrow<Quantity<T, D, K>>,
+{
+    extreme(values, Float::max)
+}
+
+/// Root mean square. Returns `None` if there are no values.
+pub fn rms<T, D, K, I>(values: I) -> Option<Quantity<T, D, K>>
+where
+    T: ValueType + RealValueType + Float,
+    D: Dimension,
+    I: IntoIterator,
+    I::Item: Borrow<Quantity<T, D, K>>,
+{
+    let values = base_values(values);
+    if values.is_empty() {
+        return None;
+    }
+    let sum = values.iter().fold(T::ZERO, |acc, v| acc + *v * *v);
+    Some(Quantity::new_base(
+        (sum / T::new_from_real_f64(values.len() as f64)).sqrt(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::si::*;
+
+    #[test]
+    fn voltage_series() {
+        let voltages = [1.0, -1.0, 1.0, -1.0, 3.0].map(ElectricPotential64::new::<Volt>);
+
+        let m: ElectricPotential64 = mean(&voltages[..]).unwrap();
+        assert!((m.get::<Volt>() - 0.6).abs() < 1e-12);
+        let r: ElectricPotential64 = rms(voltages.iter()).unwrap();
+        assert!((r.get::<Volt>() - 13.0.sqrt() / 5.0.sqrt()).abs() < 1e-12);
+        let var = variance(&voltages[..]).unwrap();
+        assert!((var.base_value() - 2.24).abs() < 1e-12);
+        assert!((sample_variance(&voltages[..]).unwrap().base_value() - 2.8).abs() < 1e-12);
+
+        assert_eq!(
+            median(&voltages[..]).unwrap(),
+            ElectricPotential64::new::<Volt>(1.0)
+        );
+        assert_eq!(
+            min(voltages).unwrap(),
+            ElectricPotential64::new::<Volt>(-1.0)
+        );
+        assert_eq!(
+            max(voltages).unwrap(),
+            ElectricPotential64::new::<Volt>(3.0)
+        );
+        assert_eq!(
+            percentile(&voltages[..], 90.0).unwrap(),
+            ElectricPotential64::new::<Volt>(2.2)
+        );
+        assert!(percentile(&voltages[..], 101.0).is_none());
+        assert!(mean(Vec::<ElectricPotential64>::new()).is_none());
+
+        let with_nan = [f64::NAN, 4.0, 1.0, f64::NAN, 2.0].map(ElectricPotential64::new::<Volt>);
+        assert_eq!(
+            median(&with_nan[..]).unwrap(),
+            ElectricPotential64::new::<Volt>(2.0)
+        );
+        assert_eq!(
+            min(with_nan).unwrap(),
+            ElectricPotential64::new::<Volt>(1.0)
+        );
+        assert_eq!(
+            max(with_nan).unwrap(),
+            ElectricPotential64::new::<Volt>(4.0)
+        );
+        let only_nan = [f64::NAN; 2].map(ElectricPotential64::new::<Volt>);
+        assert!(median(&only_nan[..]).is_none());
+        assert!(max(only_nan).is_none());
+    }
+
+    #[test]
+    fn temperatures() {
+        let temperatures = [18.0, 20.0, 22.0].map(ThermodynamicTemperature64::new::<Celsius>);
+
+        let m: ThermodynamicTemperature64 = mean(&temperatures[..]).unwrap();
+        assert!((m.get::<Celsius>() - 20.0).abs() < 1e-12);
+        let s: TemperatureInterval64 = sample_std_dev(&temperatures[..]).unwrap();
+        assert!((s.get::<Kelvin>() - 2.0).abs() < 1e-12);
+        let _: TemperatureInterval64 = std_dev(&temperatures[..]).unwrap();
+    }
+}
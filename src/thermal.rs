@@ -0,0 +1,135 @@
+use num::Float;
+use typenum::P4;
+
+use crate::{
+    constants::{
+        constant,
+        STEFAN_BOLTZMANN,
+    },
+    quantity::WithValue,
+    si::*,
+    value_type::{
+        RealValueType,
+        ValueType,
+    },
+};
+
+/// Heat flow by conduction through a slab, from the `hot` to the `cold` side
+pub fn conduction<T>(
+    conductivity: WithValue<T, ThermalConductivity64>,
+    area: WithValue<T, Area64>,
+    thickness: WithValue<T, Length64>,
+    hot: WithValue<T, ThermodynamicTemperature64>,
+    cold: WithValue<T, ThermodynamicTemperature64>,
+) -> WithValue<T, Power64>
+where
+    T: ValueType + RealValueType,
+{
+    (hot - cold) / conduction_resistance(conductivity, area, thickness)
+}
+
+/// Thermal resistance of a slab against conduction
+pub fn conduction_resistance<T>(
+    conductivity: WithValue<T, ThermalConductivity64>,
+    area: WithValue<T, Area64>,
+    thickness: WithValue<T, Length64>,
+) -> WithValue<T, ThermalResistance64>
+where
+    T: ValueType + RealValueType,
+{
+    thickness / (conductivity * area)
+}
+
+/// Heat flow by convection from a surface into the surrounding fluid
+pub fn convection<T>(
+    coefficient: WithValue<T, HeatTransferCoefficient64>,
+    area: WithValue<T, Area64>,
+    surface: WithValue<T, ThermodynamicTemperature64>,
+    fluid: WithValue<T, ThermodynamicTemperature64>,
+) -> WithValue<T, Power64>
+where
+    T: ValueType + RealValueType,
+{
+    (surface - fluid) / convection_resistance(coefficient, area)
+}
+
+/// Thermal resistance of a surface against convection
+pub fn convection_resistance<T>(
+    coefficient: WithValue<T, HeatTransferCoefficient64>,
+    area: WithValue<T, Area64>,
+) -> WithValue<T, ThermalResistance64>
+where
+    T: ValueType + RealValueType,
+{
+    WithValue::<T, Unitless64>::new_base(T::ONE) / (coefficient * area)
+}
+
+/// Net heat flow by radiation from a grey surface to its surroundings
+pub fn radiation<T>(
+    emissivity: WithValue<T, Unitless64>,
+    area: WithValue<T, Area64>,
+    surface: WithValue<T, ThermodynamicTemperature64>,
+    surroundings: WithValue<T, ThermodynamicTemperature64>,
+) -> WithValue<T, Power64>
+where
+    T: ValueType + RealValueType + Float,
+{
+    let difference =
+        surface.unrestricted().powi(P4::new()) - surroundings.unrestricted().powi(P4::new());
+    emissivity * constant(STEFAN_BOLTZMANN) * area * difference
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::quantity::QuantityType;
+
+    #[test]
+    fn heating_water() {
+        let mass = Mass64::new::<Kilogram>(2.0);
+        let specific_heat = SpecificHeatCapacity64::new::<JoulePerKilogramKelvin>(4186.0);
+        let start = ThermodynamicTemperature64::new::<Celsius>(20.0);
+        let end = ThermodynamicTemperature64::new::<Celsius>(70.0);
+
+        let heat: Energy64 = mass * specific_heat * (end - start);
+        assert!((heat.get::<KiloJoule>() - 418.6).abs() < 1e-9);
+        let capacity: HeatCapacity64 = mass * specific_heat;
+        let entropy: Entropy64 = heat / end.unrestricted();
+        assert!(entropy < capacity);
+    }
+
+    #[test]
+    fn wall() {
+        let area = Area64::new::<SquareMetre>(10.0);
+        let inside = ThermodynamicTemperature64::new::<Celsius>(20.0);
+        let outside = ThermodynamicTemperature64::new::<Celsius>(0.0);
+
+        let brick = ThermalConductivity64::new::<WattPerMetreKelvin>(0.5);
+        let q = conduction(brick, area, Length64::new::<Metre>(0.25), inside, outside);
+        assert!((q.get::<Watt>() - 400.0).abs() < 1e-9);
+
+        let h = HeatTransferCoefficient64::new::<WattPerSquareMetreKelvin>(25.0);
+        let q = convection(h, area, outside, inside);
+        assert!((q.get::<Watt>() + 5000.0).abs() < 1e-9);
+        let r = conduction_resistance(brick, area, Length64::new::<Metre>(0.25))
+            + convection_resistance(h, area);
+        assert!((r.get::<KelvinPerWatt>() - 0.054).abs() < 1e-12);
+
+        let q = radiation(
+            Unitless64::new::<Ratio>(1.0),
+            Area64::new::<SquareMetre>(1.0),
+            ThermodynamicTemperature64::new::<Kelvin>(1000.0),
+            ThermodynamicTemperature64::new::<Kelvin>(0.0),
+        );
+        assert!((q.get::<Watt>() - 56703.74419).abs() < 1e-6);
+
+        let q = conduction(
+            ThermalConductivity::<f32>::new::<WattPerMetreKelvin>(0.5),
+            Area::<f32>::new::<SquareMetre>(10.0),
+            Length::<f32>::new::<CentiMetre>(25.0),
+            ThermodynamicTemperature::<f32>::new::<Kelvin>(293.15),
+            ThermodynamicTemperature::<f32>::new::<Kelvin>(273.15),
+        );
+        assert!((q.get::<Watt>() - 400.0).abs() < 1e-3);
+    }
+}
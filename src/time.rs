@@ -0,0 +1,114 @@
+//! Interoperability with [`std::time`].
+//!
+//! A [`Duration`] converts into any [`Time`], and a [`Time`] converts back as long as it is
+//! representable, i.e. finite, not negative and not too large:
+//! ```
+//! # use std::time::Duration;
+//! # use silib::{quantity::QuantityType, si::*};
+//! let time: Time64 = Duration::from_millis(1500).into();
+//! assert_eq!(time.get::<MilliSecond>(), 1500.0);
+//! assert_eq!(Duration::try_from(time), Ok(Duration::from_millis(1500)));
+//! assert!(Duration::try_from(Time64::new::<Second>(-1.0)).is_err());
+//! ```
+
+use std::time::{
+    Duration,
+    Instant,
+    TryFromFloatSecsError,
+};
+
+use crate::{
+    dimension::TimeDim,
+    quantity::{
+        Quantity,
+        QuantityType,
+    },
+    si::*,
+    value_type::ValueType,
+};
+
+impl<T> From<Duration> for Quantity<T, TimeDim, ()>
+where
+    T: ValueType,
+{
+    fn from(duration: Duration) -> Self {
+        Self::new_base(T::new_from_real_f64(duration.as_secs_f64()))
+    }
+}
+
+impl TryFrom<Time64> for Duration {
+    type Error = TryFromFloatSecsError;
+
+    fn try_from(time: Time64) -> Result<Self, Self::Error> {
+        Duration::try_from_secs_f64(time.base_value())
+    }
+}
+
+impl TryFrom<Time32> for Duration {
+    type Error = TryFromFloatSecsError;
+
+    fn try_from(time: Time32) -> Result<Self, Self::Error> {
+        Duration::try_from_secs_f32(time.base_value())
+    }
+}
+
+/// Time passed since `start`
+pub fn elapsed(start: Instant) -> Time64 {
+    start.elapsed().into()
+}
+
+/// Signed time from `earlier` to `later`, negative if `later` is in fact earlier
+pub fn between(earlier: Instant, later: Instant) -> Time64 {
+    match later.checked_duration_since(earlier) {
+        Some(duration) => duration.into(),
+        None => -Time64::from(earlier.duration_since(later)),
+    }
+}
+
+/// The instant `time` after `instant`, going back for negative times.
+/// Returns `None` if the time is not finite or the result is not representable.
+pub fn offset(instant: Instant, time: Time64) -> Option<Instant> {
+    let seconds = time.base_value();
+    let duration = Duration::try_from_secs_f64(seconds.abs()).ok()?;
+    if seconds < 0.0 {
+        instant.checked_sub(duration)
+    } else {
+        instant.checked_add(duration)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn duration_round_trip() {
+        let time = Time64::new::<Day>(1.5);
+        assert_eq!(time.get::<Hour>(), 36.0);
+        assert_eq!(Time64::new::<Week>(2.0).get::<Day>(), 14.0);
+        assert_eq!(Duration::try_from(time), Ok(Duration::from_secs(129_600)));
+
+        let time: Time32 = Duration::from_millis(500).into();
+        assert_eq!(time.get::<Second>(), 0.5);
+        assert_eq!(Duration::try_from(time), Ok(Duration::from_millis(500)));
+
+        assert!(Duration::try_from(Time64::new::<Second>(f64::NAN)).is_err());
+        assert!(Duration::try_from(Time64::new::<Second>(f64::INFINITY)).is_err());
+        assert!(Duration::try_from(Time64::new::<JulianYear>(1e20)).is_err());
+        assert!(Duration::try_from(Time64::new::<Second>(-1e-3)).is_err());
+    }
+
+    #[test]
+    fn instants() {
+        let start = Instant::now();
+        let later = offset(start, Time64::new::<MilliSecond>(20.0)).unwrap();
+        assert_eq!(between(start, later), Time64::new::<MilliSecond>(20.0));
+        assert_eq!(between(later, start), Time64::new::<MilliSecond>(-20.0));
+        assert_eq!(
+            offset(later, Time64::new::<MilliSecond>(-20.0)),
+            Some(start)
+        );
+        assert_eq!(offset(start, Time64::new::<Second>(f64::NAN)), None);
+        assert!(elapsed(start) >= Time64::new_base(0.0));
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     fmt::{
         Display,
         Formatter,
@@ -17,6 +18,15 @@ pub trait Unit {
     fn long_name() -> &'static str;
     /// The short name of a unit. This is "s, m, m/s, ..."
     fn print_name() -> &'static str;
+
+    /// The symbol values of the dimension `Dim` are formatted with. This is the print name of the unit,
+    /// except for units like natural units that stand for a different power of their symbol in every dimension.
+    fn symbol<Dim>() -> Cow<'static, str>
+    where
+        Dim: Dimension,
+    {
+        Cow::Borrowed(Self::print_name())
+    }
 }
 
 /// This unit is a prefix unit.
@@ -29,12 +39,51 @@ pub trait PrefixUnit: Unit {
 }
 
 /// Implements conversion between quantities and the units used to build them
+///
+/// Most units are linear and also implement [`LinearConversion`], see [`linear_unit!`](crate::linear_unit).
+/// They only return their factor and offset from `linear`, the conversion functions use those by default.
+/// Reciprocal or logarithmic units implement the conversion functions instead,
+/// see [`nonlinear_unit!`](crate::nonlinear_unit).
+///
+/// Implementations from before non-linear units existed supplied `factor` and `offset` here.
+/// Those moved to [`LinearConversion`], so such units now implement that and return both from `linear`.
 pub trait QuantityConversion<DataType, Dim, K>
 where
     DataType: ValueType,
     Dim: Dimension,
 {
-    /// The factor to convert to/from the base unit
+    /// Factor and offset if this unit is linear, see [`LinearConversion`]. `None` for non-linear units.
+    fn linear() -> Option<(DataType, DataType)> {
+        None
+    }
+
+    /// Convert to this from the base unit.
+    /// Non-linear units have to implement this, as they have no factor and offset.
+    fn convert_from_base(base_value: DataType) -> DataType {
+        let (factor, offset) =
+            Self::linear().expect("non-linear units implement their conversions");
+        base_value / factor - offset
+    }
+
+    /// Convert this to the base unit.
+    /// Non-linear units have to implement this, as they have no factor and offset.
+    fn convert_to_base(my_value: DataType) -> DataType {
+        let (factor, offset) =
+            Self::linear().expect("non-linear units implement their conversions");
+        (my_value + offset) * factor
+    }
+}
+
+/// A unit that converts to its base unit with a factor and an offset.
+/// Only these units can be prefixed.
+///
+/// Linear units implement [`QuantityConversion`] with the same factor and offset as well.
+pub trait LinearConversion<DataType, Dim, K>
+where
+    DataType: ValueType,
+    Dim: Dimension,
+{
+    /// The factor to convert to/from the base unit.
     fn factor() -> DataType;
 
     /// Offset of the unit to/from the base unit. Because of course thats a thing.
@@ -43,22 +92,12 @@ where
     fn offset() -> DataType {
         DataType::ZERO
     }
-
-    /// Convert to this from the base unit
-    fn convert_from_base(base_value: DataType) -> DataType {
-        base_value / Self::factor() - Self::offset()
-    }
-
-    /// Convert this to the base unit
-    fn convert_to_base(my_value: DataType) -> DataType {
-        (my_value + Self::offset()) * Self::factor()
-    }
 }
 
-impl<DataType, Dim, K, U> QuantityConversion<DataType, Dim, K> for U
+impl<DataType, Dim, K, U> LinearConversion<DataType, Dim, K> for U
 where
     U: PrefixUnit,
-    U::Base: Unit + QuantityConversion<DataType, Dim, K>,
+    U::Base: Unit + LinearConversion<DataType, Dim, K>,
     DataType: ValueType,
     Dim: Dimension,
 {
@@ -70,6 +109,19 @@ where
         U::Base::offset()
     }
 }
+
+impl<DataType, Dim, K, U> QuantityConversion<DataType, Dim, K> for U
+where
+    U: PrefixUnit + LinearConversion<DataType, Dim, K>,
+    U::Base: Unit + LinearConversion<DataType, Dim, K>,
+    DataType: ValueType,
+    Dim: Dimension,
+{
+    fn linear() -> Option<(DataType, DataType)> {
+        Some((U::factor(), U::offset()))
+    }
+}
+
 /// A helper struct for formatting, as we cannot return `std::fmt::Arguments`.
 ///
 /// This just combines a value in `DataType` with the `UnitType` and describes how it will be formatted.
@@ -80,6 +132,7 @@ where
 {
     _unit: PhantomData<UnitType>,
     value: DataType,
+    print_name: Cow<'static, str>,
 }
 
 impl<DataType, UnitType> UnitFormatter<DataType, UnitType>
@@ -89,9 +142,18 @@ where
 {
     /// Create a new UnitFormatter.
     pub fn new(value: DataType) -> Self {
+        Self::with_print_name(value, UnitType::print_name())
+    }
+
+    /// Create a new UnitFormatter that prints a different symbol than the print name of the unit.
+    pub(crate) fn with_print_name(
+        value: DataType,
+        print_name: impl Into<Cow<'static, str>>,
+    ) -> Self {
         Self {
             _unit: PhantomData,
             value,
+            print_name: print_name.into(),
         }
     }
 }
@@ -102,10 +164,52 @@ where
     UnitType: Unit,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if let Some(precision) = f.precision() {
-            write!(f, "{:.precision$} {}", self.value, UnitType::print_name())
-        } else {
-            write!(f, "{} {}", self.value, UnitType::print_name())
+        format_with_unit(f, &self.value, &self.print_name)
+    }
+}
+
+/// Like [`UnitFormatter`], but for a unit chosen at runtime
+pub struct DynUnitFormatter<DataType>
+where
+    DataType: ValueType,
+{
+    value: DataType,
+    print_name: Cow<'static, str>,
+}
+
+impl<DataType> DynUnitFormatter<DataType>
+where
+    DataType: ValueType + Display,
+{
+    /// Create a new DynUnitFormatter.
+    pub fn new(value: DataType, print_name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            value,
+            print_name: print_name.into(),
         }
     }
 }
+
+impl<DataType> Display for DynUnitFormatter<DataType>
+where
+    DataType: ValueType + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        format_with_unit(f, &self.value, &self.print_name)
+    }
+}
+
+fn format_with_unit<DataType>(
+    f: &mut Formatter<'_>,
+    value: &DataType,
+    print_name: &str,
+) -> std::fmt::Result
+where
+    DataType: Display,
+{
+    if let Some(precision) = f.precision() {
+        write!(f, "{:.precision$} {}", value, print_name)
+    } else {
+        write!(f, "{} {}", value, print_name)
+    }
+}
@@ -0,0 +1,310 @@
+//! Preferred display units, e.g. metric for some users and US customary for others.
+//!
+//! ```
+//! # use silib::{si::*, unit_system::UnitSystem};
+//! let distance = Length64::new::<Metre>(1609.344);
+//! assert_eq!(format!("{}", distance.display_in(&UnitSystem::si())), "1609.344 m");
+//! assert_eq!(format!("{:.3}", distance.display_in(&UnitSystem::engineering())), "1.609 km");
+//! assert_eq!(format!("{}", distance.display_in(&UnitSystem::us_customary())), "5280 ft");
+//! ```
+
+use std::borrow::Cow;
+
+use crate::{
+    dimension::Dimension,
+    quantity::{
+        Quantity,
+        QuantityType,
+    },
+    registry::{
+        DynUnit,
+        UnitInfo,
+        UnitRegistry,
+    },
+    si::*,
+    unit::DynUnitFormatter,
+    us_customary::*,
+    util::superscript,
+    value_type::{
+        RealValueType,
+        ValueType,
+    },
+};
+
+/// How to choose a prefix for the preferred unit when displaying a value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrefixPolicy {
+    /// Always display in the preferred unit
+    Fixed,
+    /// Use the prefix that is a power of 1000 and brings the value into `[1, 1000)`, if the unit has prefixes
+    Engineering,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Preference {
+    unit: UnitInfo,
+    prefixes: PrefixPolicy,
+}
+
+/// Preferred display units for quantity types.
+///
+/// Quantities without a preferred unit are displayed in their base unit with the default prefix policy.
+#[derive(Debug, Clone)]
+pub struct UnitSystem {
+    registry: Cow<'static, UnitRegistry>,
+    preferences: Vec<Preference>,
+    prefixes: PrefixPolicy,
+}
+
+/// Prefixes that are powers of 1000, largest first
+const ENGINEERING_PREFIXES: [&str; 20] = [
+    "Quetta", "Ronna", "Yotta", "Zetta", "Exa", "Peta", "Tera", "Giga", "Mega", "Kilo", "Milli",
+    "Micro", "Nano", "Pico", "Femto", "Atto", "Zepto", "Yocto", "Ronto", "Quecto",
+];
+
+/// Symbols of the SI base units, in the order of the dimension exponents
+const BASE_UNIT_SYMBOLS: [&str; 7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+
+impl UnitSystem {
+    /// A unit system without preferences, using the units and prefixes of `registry`
+    pub fn new(registry: UnitRegistry, prefixes: PrefixPolicy) -> Self {
+        Self::with_registry(Cow::Owned(registry), prefixes)
+    }
+
+    fn with_registry(registry: Cow<'static, UnitRegistry>, prefixes: PrefixPolicy) -> Self {
+        Self {
+            registry,
+            preferences: Vec::new(),
+            prefixes,
+        }
+    }
+
+    /// Everything in SI base units, without prefixes
+    pub fn si() -> Self {
+        Self::with_registry(
+            Cow::Borrowed(UnitRegistry::shared_si()),
+            PrefixPolicy::Fixed,
+        )
+    }
+
+    /// SI units with engineering prefixes, like 4.7 kΩ or 220 nF
+    pub fn engineering() -> Self {
+        Self::with_registry(
+            Cow::Borrowed(UnitRegistry::shared_si()),
+            PrefixPolicy::Engineering,
+        )
+    }
+
+    /// US customary units where there are any, SI units otherwise
+    pub fn us_customary() -> Self {
+        let mut system = Self::si();
+        system.prefer(DynUnit::<Length64>::new::<Foot>(), PrefixPolicy::Fixed);
+        system.prefer(DynUnit::<Mass64>::new::<Pound>(), PrefixPolicy::Fixed);
+        system.prefer(
+            DynUnit::<ThermodynamicTemperature64>::new::<Fahrenheit>(),
+            PrefixPolicy::Fixed,
+        );
+        system.prefer(DynUnit::<Area64>::new::<SquareFoot>(), PrefixPolicy::Fixed);
+        system.prefer(DynUnit::<Volume64>::new::<UsGallon>(), PrefixPolicy::Fixed);
+        system.prefer(
+            DynUnit::<Velocity64>::new::<MilesPerHour>(),
+            PrefixPolicy::Fixed,
+        );
+        system.prefer(DynUnit::<Force64>::new::<PoundForce>(), PrefixPolicy::Fixed);
+        system.prefer(
+            DynUnit::<Pressure64>::new::<PoundPerSquareInch>(),
+            PrefixPolicy::Fixed,
+        );
+        system.prefer(
+            DynUnit::<Energy64>::new::<BritishThermalUnit>(),
+            PrefixPolicy::Fixed,
+        );
+        system.prefer(DynUnit::<Power64>::new::<Horsepower>(), PrefixPolicy::Fixed);
+        system.prefer(
+            DynUnit::<Density64>::new::<PoundPerCubicFoot>(),
+            PrefixPolicy::Fixed,
+        );
+        system.prefer(
+            DynUnit::<FuelConsumption64>::new::<MilesPerGallon>(),
+            PrefixPolicy::Fixed,
+        );
+        system
+    }
+
+    /// Display the quantity type `Q` in `unit`, replacing any earlier preference for `Q`
+    pub fn prefer<Q>(&mut self, unit: DynUnit<Q>, prefixes: PrefixPolicy) {
+        let unit = *unit.info();
+        self.preferences
+            .retain(|preference| !preference.unit.is_compatible(&unit));
+        self.preferences.push(Preference { unit, prefixes });
+    }
+
+    /// The registry units are looked up in
+    pub fn registry(&self) -> &UnitRegistry {
+        &self.registry
+    }
+
+    /// The unit to display a value of `Q` in, given in base units.
+    /// `None` if there is no preference and the registry does not know the base unit of `Q`.
+    pub fn unit_for<Q>(&self, base_value: f64) -> Option<UnitInfo>
+    where
+        Q: QuantityType,
+        Q::Kind: 'static,
+    {
+        let preference = self
+            .preferences
+            .iter()
+            .find(|preference| preference.unit.is_unit_of::<Q>())
+            .copied()
+            .or_else(|| {
+                let unit = self
+                    .registry
+                    .units_for::<Q>()
+                    .find(|unit| unit.factor() == Some(1.0) && unit.offset() == Some(0.0))?;
+                Some(Preference {
+                    unit: *unit,
+                    prefixes: self.prefixes,
+                })
+            })?;
+        Some(match preference.prefixes {
+            PrefixPolicy::Fixed => preference.unit,
+            PrefixPolicy::Engineering => self.find_prefix(&preference.unit, base_value),
+        })
+    }
+
+    /// The engineering-prefixed version of `unit` that fits `base_value` best
+    pub fn find_prefix(&self, unit: &UnitInfo, base_value: f64) -> UnitInfo {
+        let magnitude = base_value.abs();
+        if unit.offset() != Some(0.0) || !magnitude.is_normal() {
+            return *unit;
+        }
+        // kilogram is the only base unit with a prefix, the others are prefixed grams
+        let stem = match unit.long_name() {
+            "Kilogram" => "Gram",
+            name => name,
+        };
+        // compatible linear units without offset, with their factor
+        let candidates = || {
+            self.registry
+                .compatible_units(unit)
+                .filter_map(|candidate| {
+                    let factor = candidate.factor()?;
+                    (candidate.offset() == Some(0.0) && is_prefixed(candidate.long_name(), stem))
+                        .then_some((factor, candidate))
+                })
+        };
+        candidates()
+            .filter(|(factor, _)| *factor <= magnitude)
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .or_else(|| candidates().min_by(|a, b| a.0.total_cmp(&b.0)))
+            .map(|(_, candidate)| *candidate)
+            .unwrap_or(*unit)
+    }
+}
+
+fn is_prefixed(long_name: &str, stem: &str) -> bool {
+    long_name == stem
+        || (stem == "Gram" && long_name == "Kilogram")
+        || ENGINEERING_PREFIXES
+            .iter()
+            .any(|prefix| long_name.strip_prefix(prefix) == Some(stem))
+}
+
+/// The SI base units of a dimension, like "m*kg*s⁻²"
+fn base_unit_symbol(exponents: [i32; 7]) -> String {
+    let mut parts = Vec::new();
+    for (symbol, exponent) in BASE_UNIT_SYMBOLS.iter().zip(exponents) {
+        if exponent == 0 {
+            continue;
+        }
+        let mut part = symbol.to_string();
+        if exponent != 1 {
+            part.push_str(&superscript(exponent));
+        }
+        parts.push(part);
+    }
+    parts.join("*")
+}
+
+impl<DataType, Dim, K> Quantity<DataType, Dim, K>
+where
+    DataType: ValueType + RealValueType,
+    Dim: Dimension,
+    K: 'static,
+{
+    /// Format this quantity in the unit preferred by `system`
+    pub fn display_in(&self, system: &UnitSystem) -> DynUnitFormatter<DataType> {
+        let base_value = self.base_value().to_f64_mag();
+        match system.unit_for::<Self>(base_value) {
+            Some(unit) => DynUnitFormatter::new(
+                DataType::new_from_real_f64(unit.from_base(base_value)),
+                unit.print_name(),
+            ),
+            None => DynUnitFormatter::new(self.base_value(), base_unit_symbol(Dim::EXPONENTS)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn presets() {
+        let resistance = ElectricResistance64::new::<Ohm>(4700.0);
+        assert_eq!(
+            format!("{}", resistance.display_in(&UnitSystem::si())),
+            "4700 Ω"
+        );
+        let engineering = UnitSystem::engineering();
+        assert_eq!(
+            format!("{:.1}", resistance.display_in(&engineering)),
+            "4.7 kΩ"
+        );
+        let capacitance = Capacitance64::new::<Farad>(220e-9);
+        assert_eq!(
+            format!("{:.0}", capacitance.display_in(&engineering)),
+            "220 nF"
+        );
+        let mass = Mass64::new::<Gram>(0.5);
+        assert_eq!(format!("{:.0}", mass.display_in(&engineering)), "500 mg");
+        assert_eq!(
+            format!("{}", Mass64::new::<Kilogram>(2.0).display_in(&engineering)),
+            "2 kg"
+        );
+        let tiny = Length64::new::<QuectoMetre>(1e-3);
+        assert_eq!(format!("{:.3}", tiny.display_in(&engineering)), "0.001 qm");
+
+        let us = UnitSystem::us_customary();
+        let temperature = ThermodynamicTemperature64::new::<Celsius>(100.0);
+        assert_eq!(format!("{:.1}", temperature.display_in(&us)), "212.0 °F");
+        let pressure = Pressure64::new::<Pascal>(101_325.0);
+        assert_eq!(format!("{:.2}", pressure.display_in(&us)), "14.70 psi");
+        let power = Power64::new::<Watt>(745.699_871_582_27);
+        assert_eq!(format!("{:.3}", power.display_in(&us)), "1.000 hp");
+        // no US customary unit, so it stays in SI
+        let current = ElectricCurrent64::new::<Ampere>(2.0);
+        assert_eq!(format!("{}", current.display_in(&us)), "2 A");
+    }
+
+    #[test]
+    fn preferences() {
+        let mut system = UnitSystem::si();
+        let registry = UnitRegistry::si();
+        let bar = DynUnit::<Pressure64>::find(&registry, "Ba").unwrap();
+        system.prefer(bar, PrefixPolicy::Engineering);
+        let pressure = Pressure64::new::<Pascal>(250.0);
+        assert_eq!(format!("{:.1}", pressure.display_in(&system)), "2.5 mBa");
+        system.prefer(DynUnit::<Pressure64>::new::<Pascal>(), PrefixPolicy::Fixed);
+        assert_eq!(format!("{}", pressure.display_in(&system)), "250 Pa");
+
+        // quantities the registry does not know fall back to SI base units
+        let odd: Quantity<f32, <Jerk64 as QuantityType>::Dimension, SolidAngleKind> =
+            Quantity::new_base(3.0);
+        assert_eq!(format!("{}", odd.display_in(&system)), "3 m*s⁻³");
+        let per_area = Area32::new::<SquareMetre>(2.0).recip();
+        assert_eq!(format!("{}", per_area.display_in(&system)), "0.5 m⁻²");
+        assert_eq!(base_unit_symbol([1, 1, -2, 0, 0, 0, 0]), "m*kg*s⁻²");
+        assert_eq!(base_unit_symbol([0, 0, 0, 0, 0, 0, 0]), "");
+    }
+}
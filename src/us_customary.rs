@@ -0,0 +1,74 @@
+//! US customary units, based on the international yard and pound of 1959.
+//!
+//! They convert like any other unit, and [`UnitSystem::us_customary`](crate::unit_system::UnitSystem::us_customary)
+//! displays quantities in them:
+//! ```
+//! # use silib::{quantity::QuantityType, si::*, us_customary::*};
+//! let height = Length64::new::<Foot>(6.0);
+//! assert!((height.get::<Metre>() - 1.8288).abs() < 1e-12);
+//! ```
+
+use crate::{
+    additional_unit,
+    make_units,
+    si::{
+        Area,
+        Density,
+        Energy,
+        Force,
+        Length,
+        Mass,
+        Power,
+        Pressure,
+        Velocity,
+        Volume,
+    },
+};
+
+make_units!(
+    Inch: "in", "US customary unit of length";
+    Foot: "ft", "US customary unit of length";
+    Yard: "yd", "US customary unit of length";
+    Mile: "mi", "US customary unit of length";
+    SquareInch: "in²", "US customary unit of area";
+    SquareFoot: "ft²", "US customary unit of area";
+    Acre: "ac", "US customary unit of area";
+    CubicInch: "in³", "US customary unit of volume";
+    CubicFoot: "ft³", "US customary unit of volume";
+    UsGallon: "gal", "US customary unit of volume";
+    Pound: "lb", "US customary unit of mass";
+    Ounce: "oz", "US customary unit of mass";
+    FootPerSecond: "ft*s⁻¹", "US customary unit of velocity";
+    PoundForce: "lbf", "US customary unit of force";
+    PoundPerSquareInch: "psi", "US customary unit of pressure";
+    BritishThermalUnit: "BTU", "US customary unit of energy";
+    Horsepower: "hp", "US customary unit of power";
+    PoundPerCubicFoot: "lb*ft⁻³", "US customary unit of density";
+);
+
+additional_unit!(Length, Inch, 0.0254);
+additional_unit!(Length, Foot, 0.3048);
+additional_unit!(Length, Yard, 0.9144);
+additional_unit!(Length, Mile, 1609.344);
+additional_unit!(Area, SquareInch, 0.0254 * 0.0254);
+additional_unit!(Area, SquareFoot, 0.3048 * 0.3048);
+additional_unit!(Area, Acre, 4_046.856_422_4);
+additional_unit!(Volume, CubicInch, 0.0254 * 0.0254 * 0.0254);
+additional_unit!(Volume, CubicFoot, 0.3048 * 0.3048 * 0.3048);
+additional_unit!(Volume, UsGallon, 3.785_411_784e-3);
+additional_unit!(Mass, Pound, 0.453_592_37);
+additional_unit!(Mass, Ounce, 0.453_592_37 / 16.0);
+additional_unit!(Velocity, FootPerSecond, 0.3048);
+additional_unit!(Force, PoundForce, 0.453_592_37 * 9.806_65);
+additional_unit!(
+    Pressure,
+    PoundPerSquareInch,
+    0.453_592_37 * 9.806_65 / (0.0254 * 0.0254)
+);
+additional_unit!(Energy, BritishThermalUnit, 1_055.055_852_62);
+additional_unit!(Power, Horsepower, 550.0 * 0.3048 * 0.453_592_37 * 9.806_65);
+additional_unit!(
+    Density,
+    PoundPerCubicFoot,
+    0.453_592_37 / (0.3048 * 0.3048 * 0.3048)
+);
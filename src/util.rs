@@ -5,7 +5,7 @@ pub fn find_prefix<T>(value: T) -> (T, &'static str)
 where
     T: ValueType,
 {
-    static PREFIXES: &[&'static str] = &[
+    static PREFIXES: &[&str] = &[
         "q", "r", "y", "z", "a", "f", "p", "n", "μ", "m", "", "k", "M", "G", "T", "P", "E", "Z",
         "Y", "R", "Q",
     ];
@@ -22,6 +22,19 @@ where
     (new_value, PREFIXES[index])
 }
 
+/// An exponent in superscript digits, like "⁻²"
+pub(crate) fn superscript(exponent: i32) -> String {
+    const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    let mut result = String::new();
+    if exponent < 0 {
+        result.push('⁻');
+    }
+    for digit in exponent.unsigned_abs().to_string().bytes() {
+        result.push(SUPERSCRIPTS[(digit - b'0') as usize]);
+    }
+    result
+}
+
 #[cfg(test)]
 mod test {
     use crate::util::find_prefix;
@@ -0,0 +1,422 @@
+use std::{
+    array,
+    ops::{
+        Add,
+        Div,
+        Index,
+        IndexMut,
+        Mul,
+        Neg,
+        Sub,
+    },
+};
+
+use num::Float;
+
+use crate::{
+    dimension::Dimension,
+    kind::{
+        KindAdd,
+        KindDiv,
+        KindMul,
+        KindSub,
+    },
+    quantity::{
+        Quantity,
+        QuantityType,
+    },
+    unit::QuantityConversion,
+    value_type::{
+        RealValueType,
+        ValueType,
+    },
+};
+
+/// A fixed size vector of quantities that all share the same dimension and kind.
+///
+/// This is what you want for positions, velocities, forces and the likes.
+/// Operations follow the same rules as on `Quantity`: addition needs matching dimensions and `KindAdd`,
+/// multiplication with a quantity yields a vector of the product dimension and so on.
+#[derive(Debug)]
+pub struct QVec<T, const N: usize, Dim, K>
+where
+    T: ValueType,
+    Dim: Dimension,
+{
+    components: [Quantity<T, Dim, K>; N],
+}
+
+/// A 2D vector of the quantity `Q`. `Vector2<Length64>` is a position in the plane.
+pub type Vector2<Q> = QVec<
+    <Q as QuantityType>::DataType,
+    2,
+    <Q as QuantityType>::Dimension,
+    <Q as QuantityType>::Kind,
+>;
+
+/// A 3D vector of the quantity `Q`. `Vector3<Velocity64>` is a velocity in space.
+pub type Vector3<Q> = QVec<
+    <Q as QuantityType>::DataType,
+    3,
+    <Q as QuantityType>::Dimension,
+    <Q as QuantityType>::Kind,
+>;
+
+impl<T, const N: usize, Dim, K> QVec<T, N, Dim, K>
+where
+    T: ValueType,
+    Dim: Dimension,
+{
+    /// Create a new vector from its components
+    pub const fn new(components: [Quantity<T, Dim, K>; N]) -> Self {
+        Self { components }
+    }
+
+    /// Create a new vector from components given in base units
+    pub fn new_base(values: [T; N]) -> Self {
+        Self {
+            components: values.map(Quantity::new_base),
+        }
+    }
+
+    /// The zero vector
+    pub fn zero() -> Self {
+        Self::new_base([T::ZERO; N])
+    }
+
+    /// Access the components of this vector
+    pub fn components(&self) -> &[Quantity<T, Dim, K>; N] {
+        &self.components
+    }
+
+    /// Get the components of this vector, converted to the given unit
+    pub fn get<U>(&self) -> [T; N]
+    where
+        U: QuantityConversion<T, Dim, K>,
+    {
+        self.components
+            .map(|c| U::convert_from_base(c.base_value()))
+    }
+
+    /// Remove the kind restriction from this vector. See `Quantity::unrestricted` for details.
+    pub fn unrestricted(&self) -> QVec<T, N, Dim, ()> {
+        QVec {
+            components: self.components.map(|c| c.unrestricted()),
+        }
+    }
+
+    /// The dot product. Yields a quantity of the product dimension, so force · length is energy.
+    pub fn dot<DimRhs, KRhs>(
+        self,
+        rhs: QVec<T, N, DimRhs, KRhs>,
+    ) -> Quantity<T, <Dim as Mul<DimRhs>>::Output, <K as KindMul<KRhs>>::Output>
+    where
+        Dim: Mul<DimRhs>,
+        DimRhs: Dimension,
+        K: KindMul<KRhs>,
+        <Dim as Mul<DimRhs>>::Output: Dimension,
+    {
+        let value = self
+            .components
+            .iter()
+            .zip(rhs.components.iter())
+            .fold(T::ZERO, |acc, (a, b)| acc + a.base_value() * b.base_value());
+        Quantity::new_base(value)
+    }
+}
+
+impl<T, const N: usize, Dim, K> QVec<T, N, Dim, K>
+where
+    T: ValueType + RealValueType + Float,
+    Dim: Dimension,
+{
+    /// The euclidean length of this vector
+    pub fn norm(&self) -> Quantity<T, Dim, K> {
+        let squared = self
+            .components
+            .iter()
+            .fold(T::ZERO, |acc, c| acc + c.base_value() * c.base_value());
+        Quantity::new_base(squared.sqrt())
+    }
+}
+
+impl<T, Dim, K> QVec<T, 2, Dim, K>
+where
+    T: ValueType,
+    Dim: Dimension,
+{
+    /// Create a new 2D vector
+    pub const fn from_xy(x: Quantity<T, Dim, K>, y: Quantity<T, Dim, K>) -> Self {
+        Self::new([x, y])
+    }
+
+    /// The x component
+    pub fn x(&self) -> Quantity<T, Dim, K> {
+        self.components[0]
+    }
+
+    /// The y component
+    pub fn y(&self) -> Quantity<T, Dim, K> {
+        self.components[1]
+    }
+}
+
+impl<T, Dim, K> QVec<T, 3, Dim, K>
+where
+    T: ValueType,
+    Dim: Dimension,
+{
+    /// Create a new 3D vector
+    pub const fn from_xyz(
+        x: Quantity<T, Dim, K>,
+        y: Quantity<T, Dim, K>,
+        z: Quantity<T, Dim, K>,
+    ) -> Self {
+        Self::new([x, y, z])
+    }
+
+    /// The x component
+    pub fn x(&self) -> Quantity<T, Dim, K> {
+        self.components[0]
+    }
+
+    /// The y component
+    pub fn y(&self) -> Quantity<T, Dim, K> {
+        self.components[1]
+    }
+
+    /// The z component
+    pub fn z(&self) -> Quantity<T, Dim, K> {
+        self.components[2]
+    }
+
+    /// The cross product. Like the dot product, this yields the product dimension and kind.
+    /// So length × force has the dimension of a torque, but kind `()` like an energy,
+    /// not the `AngleKind` of `Torque`.
+    pub fn cross<DimRhs, KRhs>(
+        self,
+        rhs: QVec<T, 3, DimRhs, KRhs>,
+    ) -> QVec<T, 3, <Dim as Mul<DimRhs>>::Output, <K as KindMul<KRhs>>::Output>
+    where
+        Dim: Mul<DimRhs>,
+        DimRhs: Dimension,
+        K: KindMul<KRhs>,
+        <Dim as Mul<DimRhs>>::Output: Dimension,
+    {
+        let a = self.components.map(|c| c.base_value());
+        let b = rhs.components.map(|c| c.base_value());
+        QVec::new_base([
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ])
+    }
+}
+
+impl<T, const N: usize, Dim, K> Clone for QVec<T, N, Dim, K>
+where
+    T: ValueType,
+    Dim: Dimension,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize, Dim, K> Copy for QVec<T, N, Dim, K>
+where
+    T: ValueType,
+    Dim: Dimension,
+{
+}
+
+impl<T, const N: usize, Dim, K> Index<usize> for QVec<T, N, Dim, K>
+where
+    T: ValueType,
+    Dim: Dimension,
+{
+    type Output = Quantity<T, Dim, K>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.components[index]
+    }
+}
+
+impl<T, const N: usize, Dim, K> IndexMut<usize> for QVec<T, N, Dim, K>
+where
+    T: ValueType,
+    Dim: Dimension,
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.components[index]
+    }
+}
+
+impl<T, const N: usize, Dim, K> PartialEq for QVec<T, N, Dim, K>
+where
+    T: ValueType + PartialEq,
+    Dim: Dimension,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.components == other.components
+    }
+}
+
+/// Addition of vectors follows the same kind rules as addition of quantities
+impl<T, const N: usize, Dim, K1, K2> Add<QVec<T, N, Dim, K2>> for QVec<T, N, Dim, K1>
+where
+    T: ValueType,
+    Dim: Dimension,
+    K1: KindAdd<K2>,
+{
+    type Output = QVec<T, N, Dim, <K1 as KindAdd<K2>>::Output>;
+
+    fn add(self, rhs: QVec<T, N, Dim, K2>) -> Self::Output {
+        QVec::new_base(array::from_fn(|i| {
+            self.components[i].base_value() + rhs.components[i].base_value()
+        }))
+    }
+}
+
+/// Subtraction of vectors follows the same kind rules as subtraction of quantities
+impl<T, const N: usize, Dim, K1, K2> Sub<QVec<T, N, Dim, K2>> for QVec<T, N, Dim, K1>
+where
+    T: ValueType,
+    Dim: Dimension,
+    K1: KindSub<K2>,
+{
+    type Output = QVec<T, N, Dim, <K1 as KindSub<K2>>::Output>;
+
+    fn sub(self, rhs: QVec<T, N, Dim, K2>) -> Self::Output {
+        QVec::new_base(array::from_fn(|i| {
+            self.components[i].base_value() - rhs.components[i].base_value()
+        }))
+    }
+}
+
+impl<T, const N: usize, Dim, K> Neg for QVec<T, N, Dim, K>
+where
+    T: ValueType + Neg<Output = T>,
+    Dim: Dimension,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        QVec::new_base(self.components.map(|c| -c.base_value()))
+    }
+}
+
+/// Scaling a vector by a quantity yields a vector of the product dimension
+impl<T, const N: usize, DimLhs, KLhs, DimRhs, KRhs> Mul<Quantity<T, DimRhs, KRhs>>
+    for QVec<T, N, DimLhs, KLhs>
+where
+    T: ValueType,
+    DimLhs: Dimension + Mul<DimRhs>,
+    DimRhs: Dimension,
+    KLhs: KindMul<KRhs>,
+    <DimLhs as Mul<DimRhs>>::Output: Dimension,
+{
+    type Output = QVec<T, N, <DimLhs as Mul<DimRhs>>::Output, <KLhs as KindMul<KRhs>>::Output>;
+
+    fn mul(self, rhs: Quantity<T, DimRhs, KRhs>) -> Self::Output {
+        QVec::new_base(self.components.map(|c| c.base_value() * rhs.base_value()))
+    }
+}
+
+/// Same as vector * quantity, just the other way around
+impl<T, const N: usize, DimLhs, KLhs, DimRhs, KRhs> Mul<QVec<T, N, DimRhs, KRhs>>
+    for Quantity<T, DimLhs, KLhs>
+where
+    T: ValueType,
+    DimLhs: Dimension + Mul<DimRhs>,
+    DimRhs: Dimension,
+    KLhs: KindMul<KRhs>,
+    <DimLhs as Mul<DimRhs>>::Output: Dimension,
+{
+    type Output = QVec<T, N, <DimLhs as Mul<DimRhs>>::Output, <KLhs as KindMul<KRhs>>::Output>;
+
+    fn mul(self, rhs: QVec<T, N, DimRhs, KRhs>) -> Self::Output {
+        QVec::new_base(rhs.components.map(|c| self.base_value() * c.base_value()))
+    }
+}
+
+/// Dividing a vector by a quantity yields a vector of the quotient dimension
+impl<T, const N: usize, DimLhs, KLhs, DimRhs, KRhs> Div<Quantity<T, DimRhs, KRhs>>
+    for QVec<T, N, DimLhs, KLhs>
+where
+    T: ValueType,
+    DimLhs: Dimension + Div<DimRhs>,
+    DimRhs: Dimension,
+    KLhs: KindDiv<KRhs>,
+    <DimLhs as Div<DimRhs>>::Output: Dimension,
+{
+    type Output = QVec<T, N, <DimLhs as Div<DimRhs>>::Output, <KLhs as KindDiv<KRhs>>::Output>;
+
+    fn div(self, rhs: Quantity<T, DimRhs, KRhs>) -> Self::Output {
+        QVec::new_base(self.components.map(|c| c.base_value() / rhs.base_value()))
+    }
+}
+
+impl<T, const N: usize, Dim, K> Mul<T> for QVec<T, N, Dim, K>
+where
+    T: ValueType,
+    Dim: Dimension,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        QVec::new_base(self.components.map(|c| c.base_value() * rhs))
+    }
+}
+
+impl<T, const N: usize, Dim, K> Div<T> for QVec<T, N, Dim, K>
+where
+    T: ValueType,
+    Dim: Dimension,
+{
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        QVec::new_base(self.components.map(|c| c.base_value() / rhs))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::si::*;
+
+    #[test]
+    fn add_and_scale() {
+        let v1 = Vector3::<Velocity64>::from_xyz(
+            Velocity64::new::<MetresPerSecond>(1.0),
+            Velocity64::new::<MetresPerSecond>(2.0),
+            Velocity64::new::<MetresPerSecond>(3.0),
+        );
+        let v2 = Vector3::<Velocity64>::new_base([1.0, 1.0, 1.0]);
+        let sum = v1 + v2;
+        assert_eq!(sum.get::<MetresPerSecond>(), [2.0, 3.0, 4.0]);
+
+        let pos: Vector3<Length64> = sum * Time::new::<Second>(2.0);
+        assert_eq!(pos.get::<Metre>(), [4.0, 6.0, 8.0]);
+
+        let accel = Vector3::<Acceleration64>::new_base([0.0, 0.0, -9.81]);
+        let force: Vector3<Force64> = Mass::new::<Kilogram>(2.0) * accel;
+        assert_eq!(force.z().get::<Newton>(), -19.62);
+    }
+
+    #[test]
+    fn products() {
+        let force = Vector3::<Force64>::new_base([0.0, 10.0, 0.0]);
+        let lever = Vector3::<Length64>::new_base([2.0, 0.0, 0.0]);
+
+        let work: Energy64 = force.dot(lever);
+        assert_eq!(work.get::<Joule>(), 0.0);
+
+        let moment: Vector3<Energy64> = lever.cross(force);
+        assert_eq!(moment.get::<Joule>(), [0.0, 0.0, 20.0]);
+
+        let v = Vector2::<Length64>::new_base([3.0, 4.0]);
+        assert_eq!(v.norm().get::<Metre>(), 5.0);
+    }
+}
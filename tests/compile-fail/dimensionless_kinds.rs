@@ -0,0 +1,10 @@
+use silib::si::*;
+
+fn shock_expected(mach: MachNumber64) -> bool {
+    mach > MachNumber64::new::<Ratio>(1.0)
+}
+
+fn main() {
+    let re = ReynoldsNumber64::new::<Ratio>(1e5);
+    shock_expected(re);
+}
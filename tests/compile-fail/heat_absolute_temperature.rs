@@ -0,0 +1,8 @@
+use silib::si::*;
+
+fn main() {
+    let mass = Mass64::new::<Kilogram>(2.0);
+    let specific_heat = SpecificHeatCapacity64::new::<JoulePerKilogramKelvin>(4186.0);
+    let temperature = ThermodynamicTemperature64::new::<Celsius>(20.0);
+    let _heat: Energy64 = mass * specific_heat * temperature;
+}
@@ -0,0 +1,9 @@
+use silib::{
+    polynomial::Polynomial,
+    si::*,
+};
+
+fn main() {
+    let p = Polynomial::<Time64, Length64>::constant(Length64::new::<Metre>(2.0));
+    let _ = p.coefficient::<typenum::N1>();
+}
@@ -0,0 +1,10 @@
+use silib::si::*;
+
+fn main() {
+    let p = Power64::new::<Watt>(100.0);
+    let q = ReactivePower64::new::<VoltAmpereReactive>(50.0);
+    let s = ApparentPower64::new::<KiloVoltAmpere>(1.0);
+    let _q2 = q + q;
+    let _pq = p + q;
+    let _qs = q - s;
+}
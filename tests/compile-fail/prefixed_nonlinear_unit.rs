@@ -0,0 +1,17 @@
+use silib::{
+    make_units,
+    nonlinear_unit,
+    quantity::QuantityType,
+    si::*,
+};
+
+make_units!(
+    Neper: "Np", "Logarithmic ratio of amplitudes", prefix_units=[AllPrefixes];
+);
+
+nonlinear_unit!(Unitless, Neper, |np| np.exp(), |ratio| ratio.ln());
+
+fn main() {
+    let gain = Unitless64::new::<Neper>(1.0);
+    let _ = gain.get::<MilliNeper>();
+}
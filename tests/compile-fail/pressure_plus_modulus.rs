@@ -0,0 +1,8 @@
+use silib::si::*;
+
+fn main() {
+    let pressure = Pressure64::new::<Pascal>(101325.0);
+    let modulus = ElasticModulus64::new::<GigaPascal>(200.0);
+    let _stress: Pressure64 = modulus * Strain64::new::<Ratio>(1e-3);
+    let _sum = pressure + modulus;
+}
@@ -0,0 +1,9 @@
+use silib::si::*;
+
+fn main() {
+    let torque = Torque64::new::<NewtonMetre>(10.0);
+    let stiffness = rotational_stiffness(torque, Angle64::new::<Radian>(0.5));
+    let energy = Energy64::new::<Joule>(1.0);
+    let _sum = stiffness + energy;
+    let _energy: Energy64 = stiffness;
+}